@@ -0,0 +1,127 @@
+// `#` and `//` comments are skipped by the lexer, but a regex can't match nested
+// `/* */` comments, so they are blanked out before parsing. comments are replaced
+// by spaces so error locations still point at the source
+pub fn strip_block_comments(code: &str) -> Result<String, String> {
+    let mut res = String::with_capacity(code.len());
+    let mut chars = code.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                res.push(c);
+                while let Some(c) = chars.next() {
+                    res.push(c);
+                    if c == '"' {
+                        break;
+                    }
+                }
+            }
+            // line comments are left for the lexer, a `/*` inside them doesn't start a block
+            '#' | '/' if c == '#' || chars.peek() == Some(&'/') => {
+                res.push(c);
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' || c == '\r' {
+                        break;
+                    }
+                    res.push(c);
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                res.push_str("  ");
+
+                let mut depth = 1;
+                while depth > 0 {
+                    let c = match chars.next() {
+                        Some(c) => c,
+                        None => return Err(format!("unterminated block comment")),
+                    };
+
+                    if c == '/' && chars.peek() == Some(&'*') {
+                        chars.next();
+                        depth += 1;
+                        res.push_str("  ");
+                    } else if c == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        depth -= 1;
+                        res.push_str("  ");
+                    } else if c == '\n' || c == '\r' {
+                        res.push(c);
+                    } else {
+                        res.push_str(&" ".repeat(c.len_utf8()));
+                    }
+                }
+            }
+            c => res.push(c),
+        }
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_block_comments;
+    use crate::ast::{Expr, Statement};
+    use crate::grammar::programParser;
+
+    fn parse(code: &str) -> Result<Vec<Statement>, String> {
+        let code = strip_block_comments(code)?;
+        let program = programParser::new()
+            .parse(&code)
+            .map_err(|e| e.to_string())?;
+
+        Ok(program.statements)
+    }
+
+    fn int_statement(n: i32) -> Statement {
+        Statement::Expression(Expr::Int(n))
+    }
+
+    #[test]
+    fn line_comment_at_end_of_file() {
+        assert_eq!(
+            parse("1; // no newline after this"),
+            Ok(vec![int_statement(1)])
+        );
+        assert_eq!(
+            parse("1; # no newline after this"),
+            Ok(vec![int_statement(1)])
+        );
+    }
+
+    #[test]
+    fn line_comment_inside_string() {
+        assert_eq!(
+            parse(r#""a // b /* c";"#),
+            Ok(vec![Statement::Expression(Expr::String(
+                "a // b /* c".to_string()
+            ))])
+        );
+    }
+
+    #[test]
+    fn nested_block_comment() {
+        let code = "1; /* outer /* inner */ still a comment */ 2;";
+
+        assert_eq!(parse(code), Ok(vec![int_statement(1), int_statement(2)]));
+        assert_eq!(strip_block_comments(code).unwrap().len(), code.len());
+    }
+
+    #[test]
+    fn block_comment_inside_line_comment() {
+        assert_eq!(
+            parse("1; // /* not a block\n2;"),
+            Ok(vec![int_statement(1), int_statement(2)])
+        );
+    }
+
+    #[test]
+    fn unterminated_block_comment() {
+        assert_eq!(
+            strip_block_comments("1; /* open /* nested */"),
+            Err("unterminated block comment".to_string())
+        );
+    }
+}
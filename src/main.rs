@@ -14,6 +14,7 @@ extern crate sys_info;
 lalrpop_util::lalrpop_mod!(pub grammar);
 
 mod ast;
+mod comments;
 mod runtime;
 
 fn main() -> Result<(), String> {
@@ -25,6 +26,7 @@ fn main() -> Result<(), String> {
 
             
             let code = fs::read_to_string(path).expect("unable to read the file");
+            let code = comments::strip_block_comments(&code)?;
             let parser = grammar::programParser::new();
             let ast = parser.parse(&code).map_err(|e| match e {
                 ParseError::InvalidToken { location } => format!("InvalidToken at {}", location),
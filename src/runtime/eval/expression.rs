@@ -1,591 +1,750 @@
-use std::collections::{BTreeMap, HashMap};
-use std::fs;
-
-use crate::ast::{Arg, BinaryOpKind, Branch, Expr, Prop, Statement, UnaryOpKind};
-use crate::grammar;
-use crate::runtime::value::{KeyValue, Value};
-use crate::runtime::{DeclType, Prototypes, ScopeStack, Simple, Type};
-
-use super::program::eval_program_and_push_scope;
-use super::statement::{eval_module, eval_statements, Escape};
-
-pub fn eval_expression(
-    scopes: &mut ScopeStack,
-    expression: &Expr,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-) -> Result<Value, String> {
-    match expression {
-        Expr::Null => eval_null_expr(),
-        Expr::Int(n) => eval_int_expr(n),
-        Expr::Float(n) => eval_float_expr(n),
-        Expr::String(s) => eval_stirng_expr(s),
-        Expr::Bool(b) => eval_bool_expr(b),
-        Expr::List(list) => eval_list_expr(scopes, prototypes, list),
-        Expr::Call(expr, args) => eval_call_expr(scopes, prototypes, expr, args),
-        Expr::Identifier(name) => eval_ident_expr(scopes, name),
-        Expr::MethodCall(object, calle) => eval_method_call_expr(scopes, prototypes, object, calle),
-        Expr::Index(expr, loc) => eval_index_expr(scopes, prototypes, expr, loc),
-        Expr::BinaryOp(lhs, op, rhs) => eval_binary_expr(scopes, prototypes, lhs, op, rhs),
-        Expr::UnaryOp(op, expr) => eval_unary_expr(scopes, prototypes, op, expr),
-        Expr::Object(props) => eval_object_expr(scopes, prototypes, props),
-        Expr::Fn(args, ret_type, block) => eval_fn_expr(args, ret_type, block),
-        Expr::ModuleCall(paths, expr) => eval_module_call_expr(scopes, prototypes, paths, expr),
-        Expr::Module(statements) => eval_module_expr(scopes, prototypes, statements),
-        Expr::If(branchs, else_block) => eval_if_expr(scopes, prototypes, branchs, else_block),
-        Expr::Tuple(exprs) => eval_tuple_expr(scopes, prototypes, exprs),
-        Expr::Range(start, end) => eval_range_expr(scopes, prototypes, start, end),
-    }
-}
-
-pub fn eval_null_expr() -> Result<Value, String> {
-    Ok(Value::Null)
-}
-
-pub fn eval_stirng_expr(s: &String) -> Result<Value, String> {
-    Ok(Value::String(s.to_string()))
-}
-
-pub fn eval_int_expr(n: &i32) -> Result<Value, String> {
-    Ok(Value::Int(*n))
-}
-
-pub fn eval_float_expr(n: &f32) -> Result<Value, String> {
-    Ok(Value::Float(*n))
-}
-
-pub fn eval_bool_expr(b: &bool) -> Result<Value, String> {
-    Ok(Value::Bool(*b))
-}
-
-pub fn eval_list_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    list: &Vec<Expr>,
-) -> Result<Value, String> {
-    let mut values: Vec<Value> = Vec::new();
-
-    for expr in list {
-        let value = eval_expression(scopes, expr, &prototypes)?;
-
-        values.push(value);
-    }
-
-    Ok(Value::List(values))
-}
-
-pub fn eval_call_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    expr: &Box<Expr>,
-    args: &Vec<Expr>,
-) -> Result<Value, String> {
-    let value = eval_expression(scopes, &expr, &prototypes)?;
-
-    match value {
-        Value::BuiltInFn(f) => {
-            let mut values = vec![];
-
-            for arg in args {
-                let val = eval_expression(scopes, arg, &prototypes)?;
-                values.push(val);
-            }
-
-            let value = f(values)?;
-            return Ok(value);
-        }
-        Value::Func(params, _, block) => {
-            if params.len() != args.len() {
-                return Err(format!(
-                    "expected {} arguments but found {}",
-                    params.len(),
-                    args.len()
-                ));
-            }
-
-            let mut inner_scope = scopes.new_from_push(HashMap::new());
-            for (i, param) in params.iter().enumerate() {
-                match args.get(i) {
-                    Some(expr) => {
-                        let value = eval_expression(&mut inner_scope, expr, &prototypes)?;
-
-                        inner_scope.declare(
-                            &param.ident,
-                            value,
-                            &Some(param.datatype.clone()),
-                            DeclType::Mutable,
-                        )?;
-                    }
-                    None => {
-                        return Err(format!(
-                            "expected {} arguments but found {}",
-                            params.len(),
-                            args.len()
-                        ))
-                    }
-                }
-            }
-
-            let ret = eval_statements(&mut inner_scope, &block, prototypes)?;
-            match ret {
-                Escape::None => Ok(Value::Null),
-                Escape::Return(value) => Ok(value),
-                Escape::Break => Err(format!("break outside of loop (2)")),
-                Escape::Continue => Err(format!("continue out side of loop (2)")),
-            }
-        }
-        Value::BuiltInMethod(f, this) => {
-            let mut values = vec![];
-
-            for arg in args {
-                let val = eval_expression(scopes, arg, &prototypes)?;
-                values.push(val);
-            }
-            if let Some(this) = this {
-                let res = f(values, *this)?;
-                return Ok(res);
-            } else {
-                return Err("dev error".to_string());
-            }
-        }
-        _ => {
-            return Err(format!(
-                "value of type '{:?}' is not callable (5)",
-                String::from(Type::from(&value))
-            ));
-        }
-    }
-}
-
-pub fn eval_ident_expr(scopes: &mut ScopeStack, name: &String) -> Result<Value, String> {
-    match scopes.get(&name) {
-        Some(v) => Ok(v),
-        None => Err(format!("{} is not defied (8)", name)),
-    }
-}
-
-pub fn eval_method_call_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    object: &Box<Expr>,
-    calle: &Box<Expr>,
-) -> Result<Value, String> {
-    let obj_value = eval_expression(scopes, &*object, &prototypes)?;
-
-    match *calle.clone() {
-        Expr::Identifier(name) => match prototypes.get(&Type::simple(&obj_value.clone())) {
-            Some(proto) => match proto.get(&name) {
-                Some(value) => {
-                    if let Value::BuiltInMethod(f, _) = value {
-                        return Ok(Value::BuiltInMethod(*f, Some(Box::new(obj_value))));
-                    }
-                    return Ok(value.to_owned());
-                }
-                None => {
-                    if let Value::Object(props) = &obj_value {
-                        let prop = props.into_iter().find(|kv| kv.key == name);
-
-                        if let Some(kv) = prop {
-                            return Ok(kv.value.clone());
-                        }
-                    }
-                    return Err(format!(
-                        "'{}' dose not exist in '{:?}' prototype (6)",
-                        name,
-                        String::from(Type::from(&obj_value))
-                    ));
-                }
-            },
-            None => {
-                return Err(format!(
-                    "the prototype for type {:?} is not implemented (8)",
-                    String::from(Type::from(&obj_value))
-                ));
-            }
-        },
-        Expr::Call(expr, args) => match *expr {
-            Expr::Identifier(name) => match prototypes.get(&Type::simple(&obj_value.clone())) {
-                Some(proto) => match proto.get(&name) {
-                    Some(value) => match value {
-                        Value::BuiltInMethod(f, _) => {
-                            let mut values = vec![];
-
-                            for arg in args {
-                                let val = eval_expression(scopes, &arg, &prototypes)?;
-                                values.push(val);
-                            }
-
-                            let res = f(values, obj_value.to_owned())?;
-                            return Ok(res);
-                        }
-                        _ => todo!(),
-                    },
-                    None => {
-                        if let Value::Object(props) = &obj_value {
-                            let prop = props.into_iter().find(|kv| kv.key == name);
-                            if let Some(kv) = prop {
-                                return Ok(kv.value.to_owned());
-                            }
-                        }
-                        return Err(format!(
-                            "'{}' dose not exist in '{:?}' prototype (3)",
-                            name,
-                            String::from(Type::from(&obj_value))
-                        ));
-                    }
-                },
-                None => {
-                    return Err(format!(
-                        "the prototype for type {:?} is not implemented",
-                        String::from(Type::from(&obj_value))
-                    ))
-                }
-            },
-            _ => {
-                return Err(format!(
-                    "value of type {:?} not callable (2)",
-                    String::from(Type::from(&obj_value))
-                ));
-            }
-        },
-        Expr::Int(n) => {
-            return Err(format!(
-                "{} is not found in {} prototype",
-                n,
-                String::from(Type::from(&obj_value))
-            ))
-        }
-
-        _ => {
-            return Err(format!(
-                "value of type {:?} not callable (1)",
-                String::from(Type::from(&obj_value))
-            ));
-        }
-    }
-}
-
-pub fn eval_index_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    expr: &Box<Expr>,
-    loc: &Box<Expr>,
-) -> Result<Value, String> {
-    let expr_value = eval_expression(scopes, &*expr, &prototypes)?;
-
-    match &expr_value {
-        Value::String(s) => {
-            let loc_value = eval_expression(scopes, &*loc, &prototypes)?;
-
-            match loc_value {
-                Value::Int(index) => {
-                    if let Some(res) = s.chars().nth(index as usize) {
-                        return Ok(Value::String(res.to_string()));
-                    } else {
-                        return Err(format!("index out of bounds"));
-                    }
-                }
-                _ => {
-                    return Err(format!(
-                        "the type {:?} cannot be indexed by {:?}",
-                        String::from(Type::from(&expr_value)),
-                        String::from(Type::from(&loc_value))
-                    ))
-                }
-            }
-        }
-        Value::List(l) | Value::Tuple(l) => {
-            let loc_value = eval_expression(scopes, &*loc, &prototypes)?;
-
-            match loc_value {
-                Value::Int(index) => {
-                    if let Some(res) = l.get(index as usize) {
-                        return Ok(Value::from(res));
-                    } else {
-                        return Err(format!("index out of bounds"));
-                    }
-                }
-                _ => {
-                    return Err(format!(
-                        "the type {:?} cannot be indexed by {:?}",
-                        String::from(Type::from(&expr_value)),
-                        String::from(Type::from(&loc_value))
-                    ))
-                }
-            }
-        }
-        _ => {
-            return Err(format!(
-                "cannot index into a value of type {:?}",
-                String::from(Type::from(&expr_value)),
-            ));
-        }
-    }
-}
-
-pub fn eval_binary_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    lhs: &Box<Expr>,
-    op: &BinaryOpKind,
-    rhs: &Box<Expr>,
-) -> Result<Value, String> {
-    let lhs = eval_expression(scopes, &*lhs, &prototypes)?;
-    let rhs = eval_expression(scopes, &*rhs, &prototypes)?;
-
-    let res = match op {
-        BinaryOpKind::Add => &lhs + &rhs,
-        BinaryOpKind::Sub => &lhs - &rhs,
-        BinaryOpKind::Mul => &lhs * &rhs,
-        BinaryOpKind::Div => &lhs / &rhs,
-        BinaryOpKind::EQ => Ok(Value::Bool(lhs == rhs)),
-        BinaryOpKind::NE => Ok(Value::Bool(lhs != rhs)),
-        BinaryOpKind::GT => Ok(Value::Bool(lhs > rhs)),
-        BinaryOpKind::LT => Ok(Value::Bool(lhs < rhs)),
-        BinaryOpKind::GTE => Ok(Value::Bool(lhs >= rhs)),
-        BinaryOpKind::LTE => Ok(Value::Bool(lhs <= rhs)),
-        BinaryOpKind::And => {
-            if let Value::Bool(v1) = lhs {
-                if let Value::Bool(v2) = rhs {
-                    return Ok(Value::Bool(v1 && v2));
-                } else {
-                    return Err(format!(
-                        "expected bool found {:?}",
-                        String::from(Type::from(&rhs))
-                    ));
-                }
-            } else {
-                return Err(format!(
-                    "expected bool found {:?}",
-                    String::from(Type::from(&lhs))
-                ));
-            }
-        }
-        BinaryOpKind::Or => {
-            if let Value::Bool(v1) = lhs {
-                if let Value::Bool(v2) = rhs {
-                    return Ok(Value::Bool(v1 || v2));
-                } else {
-                    return Err(format!(
-                        "expected bool found {:?}",
-                        String::from(Type::from(&rhs))
-                    ));
-                }
-            } else {
-                return Err(format!(
-                    "expected bool found {:?}",
-                    String::from(Type::from(&lhs))
-                ));
-            }
-        }
-    };
-
-    res
-}
-
-pub fn eval_unary_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    op: &UnaryOpKind,
-    expr: &Box<Expr>,
-) -> Result<Value, String> {
-    let value = eval_expression(scopes, &*expr, prototypes)?;
-
-    match op {
-        UnaryOpKind::Not => !value,
-        UnaryOpKind::Typeof => Ok(Value::String(Type::simple(&value))),
-    }
-}
-
-pub fn eval_object_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    props: &Vec<Prop>,
-) -> Result<Value, String> {
-    let mut values: Vec<KeyValue> = Vec::new();
-
-    for prop in props {
-        let value = eval_expression(scopes, &prop.value, &prototypes)?;
-
-        values.push(KeyValue {
-            key: prop.key.to_string(),
-            value,
-        });
-    }
-
-    Ok(Value::Object(values))
-}
-
-pub fn eval_fn_expr(
-    args: &Vec<Arg>,
-    ret_type: &Option<Type>,
-    block: &Vec<Statement>,
-) -> Result<Value, String> {
-    Ok(Value::Func(args.to_vec(), ret_type.clone(), block.to_vec()))
-}
-
-pub fn eval_module_call_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    paths: &Vec<String>,
-    expr: &Box<Expr>,
-) -> Result<Value, String> {
-    let module = get_module(scopes, paths)?;
-
-    let mut inner_scopes = scopes.new_from_push(HashMap::new());
-
-    for (key, value) in module {
-        inner_scopes.declare(
-            &key,
-            value.clone(),
-            &Some(Type::from(&value)),
-            DeclType::Immutable,
-        )?;
-    }
-
-    let value = eval_expression(&mut inner_scopes, &*expr, &prototypes)?;
-    Ok(value)
-}
-
-pub fn eval_module_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    statements: &Vec<Statement>,
-) -> Result<Value, String> {
-    let module = eval_module(scopes, prototypes, &String::from("test"), statements)?;
-    Ok(Value::Module(module))
-}
-
-pub fn eval_if_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    branchs: &Vec<Branch>,
-    else_block: &Option<Vec<Statement>>,
-) -> Result<Value, String> {
-    for branch in branchs {
-        let value = eval_expression(scopes, &branch.condition, &prototypes)?;
-
-        match value {
-            Value::Bool(b) => {
-                if b {
-                    let ret = eval_statements(scopes, &branch.statements, &prototypes)?;
-
-                    if let Escape::Return(value) = ret {
-                        return Ok(value);
-                    }
-
-                    return Ok(Value::Null);
-                }
-            }
-            _ => return Err(format!("condition most be a boolean")),
-        }
-    }
-
-    if let Some(stmts) = else_block {
-        let e = eval_statements(scopes, stmts, &prototypes)?;
-
-        if let Escape::Return(value) = e {
-            return Ok(value);
-        }
-    }
-
-    Ok(Value::Null)
-}
-
-pub fn eval_tuple_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    exprs: &Vec<Expr>,
-) -> Result<Value, String> {
-    let mut values = Vec::new();
-
-    for expr in exprs {
-        let value = eval_expression(scopes, expr, &prototypes)?;
-        values.push(value);
-    }
-
-    Ok(Value::Tuple(values))
-}
-
-pub fn eval_range_expr(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    start: &Box<Expr>,
-    end: &Box<Expr>,
-) -> Result<Value, String> {
-    let start = eval_expression(scopes, &start, prototypes)?;
-    let end = eval_expression(scopes, &end, prototypes)?;
-
-    match start {
-        Value::Int(s) => match end {
-            Value::Int(e) => {
-                let mut list = Vec::new();
-
-                for num in s..=e {
-                    list.push(Value::Int(num));
-                }
-
-                return Ok(Value::List(list));
-            }
-            other => {
-                return Err(format!(
-                    "extected integer, found {}",
-                    String::from(Type::from(&other))
-                ))
-            }
-        },
-        other => {
-            return Err(format!(
-                "expected integer, found {}",
-                String::from(Type::from(&other))
-            ))
-        }
-    }
-}
-
-pub fn get_module(
-    scopes: &mut ScopeStack,
-    paths: &Vec<String>,
-) -> Result<BTreeMap<String, Value>, String> {
-    let mut exports: BTreeMap<String, Value> = BTreeMap::new();
-
-    for path in paths {
-        match exports.get(path) {
-            Some(value) => match value.to_owned() {
-                Value::Module(items) => {
-                    exports = items;
-                }
-                _ => return Err(format!("module {} not found (1)", path)),
-            },
-            None => match scopes.get(path) {
-                Some(value) => match value {
-                    Value::Module(items) => {
-                        exports = items;
-                    }
-                    _ => return Err(format!("module {} not found (2)", path)),
-                },
-                None => {
-                    let mut path = String::new();
-                    path.push_str("./examples/");
-                    path.push_str(&paths.join("/"));
-                    path.push_str(".ak");
-
-                    let file_result = fs::read_to_string(path);
-
-                    if let Ok(file) = file_result {
-                        // scopes.push();
-                        let program = grammar::programParser::new().parse(&file).expect(&format!(
-                            "unable to compile module {}",
-                            paths.last().unwrap()
-                        ));
-                        eval_program_and_push_scope(scopes, program, &Prototypes::exports())?;
-                        break;
-                    } else {
-                        return Err(format!("module {} not found (3)", paths.last().unwrap()));
-                    }
-                }
-            },
-        }
-    }
-
-    Ok(exports)
-}
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+
+use crate::ast::{Arg, BinaryOpKind, Branch, Expr, Prop, Statement, StringPart, UnaryOpKind};
+use crate::comments::strip_block_comments;
+use crate::grammar;
+use crate::runtime::value::{compare, structural_eq, Captured, KeyValue, Value};
+use crate::runtime::{DeclType, Prototypes, ScopeStack, Simple, Type};
+
+use super::program::eval_program_and_push_scope;
+use super::statement::{eval_module, eval_statements, Escape};
+
+pub fn eval_expression(
+    scopes: &mut ScopeStack,
+    expression: &Expr,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+) -> Result<Value, String> {
+    match expression {
+        Expr::Null => eval_null_expr(),
+        Expr::Int(n) => eval_int_expr(n),
+        Expr::Float(n) => eval_float_expr(n),
+        Expr::String(s) => eval_stirng_expr(s),
+        Expr::Bool(b) => eval_bool_expr(b),
+        Expr::List(list) => eval_list_expr(scopes, prototypes, list),
+        Expr::Call(expr, args) => eval_call_expr(scopes, prototypes, expr, args),
+        Expr::Identifier(name) => eval_ident_expr(scopes, name),
+        Expr::MethodCall(object, calle) => eval_method_call_expr(scopes, prototypes, object, calle),
+        Expr::Index(expr, loc) => eval_index_expr(scopes, prototypes, expr, loc),
+        Expr::BinaryOp(lhs, op, rhs) => eval_binary_expr(scopes, prototypes, lhs, op, rhs),
+        Expr::UnaryOp(op, expr) => eval_unary_expr(scopes, prototypes, op, expr),
+        Expr::Object(props) => eval_object_expr(scopes, prototypes, props),
+        Expr::Fn(args, ret_type, block) => eval_fn_expr(scopes, args, ret_type, block),
+        Expr::ModuleCall(paths, expr) => eval_module_call_expr(scopes, prototypes, paths, expr),
+        Expr::Module(statements) => eval_module_expr(scopes, prototypes, statements),
+        Expr::If(branchs, else_block) => eval_if_expr(scopes, prototypes, branchs, else_block),
+        Expr::Tuple(exprs) => eval_tuple_expr(scopes, prototypes, exprs),
+        Expr::Range(start, end, inclusive, step) => {
+            eval_range_expr(scopes, prototypes, start, end, inclusive, step)
+        }
+        Expr::Interpolation(parts) => eval_interpolation_expr(scopes, prototypes, parts),
+        Expr::Conditional(cond, then, otherwise) => {
+            eval_conditional_expr(scopes, prototypes, cond, then, otherwise)
+        }
+        Expr::Spread(_) => Err(format!("`...` can only be used in call arguments")),
+    }
+}
+
+pub fn eval_null_expr() -> Result<Value, String> {
+    Ok(Value::Null)
+}
+
+pub fn eval_stirng_expr(s: &String) -> Result<Value, String> {
+    Ok(Value::String(s.to_string()))
+}
+
+pub fn eval_int_expr(n: &i32) -> Result<Value, String> {
+    Ok(Value::Int(*n))
+}
+
+pub fn eval_float_expr(n: &f32) -> Result<Value, String> {
+    Ok(Value::Float(*n))
+}
+
+pub fn eval_bool_expr(b: &bool) -> Result<Value, String> {
+    Ok(Value::Bool(*b))
+}
+
+pub fn eval_list_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    list: &Vec<Expr>,
+) -> Result<Value, String> {
+    let mut values: Vec<Value> = Vec::new();
+
+    for expr in list {
+        let value = eval_expression(scopes, expr, &prototypes)?;
+
+        values.push(value);
+    }
+
+    Ok(Value::List(values))
+}
+
+pub fn eval_call_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    expr: &Box<Expr>,
+    args: &Vec<Expr>,
+) -> Result<Value, String> {
+    let value = eval_expression(scopes, &expr, &prototypes)?;
+    let values = eval_call_args(scopes, prototypes, args)?;
+
+    call_value(scopes, prototypes, value, values)
+}
+
+fn eval_call_args(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    args: &Vec<Expr>,
+) -> Result<Vec<Value>, String> {
+    let mut values = vec![];
+
+    for arg in args {
+        if let Expr::Spread(inner) = arg {
+            match eval_expression(scopes, inner, &prototypes)? {
+                Value::List(items) => values.extend(items),
+                other => {
+                    return Err(format!(
+                        "only a list can be spread, found {}",
+                        Type::simple(&other)
+                    ))
+                }
+            }
+            continue;
+        }
+
+        let val = eval_expression(scopes, arg, &prototypes)?;
+        values.push(val);
+    }
+
+    Ok(values)
+}
+
+// shared by call expressions and builtins that take a callback
+pub fn call_value(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    value: Value,
+    values: Vec<Value>,
+) -> Result<Value, String> {
+    match value {
+        Value::BuiltInFn(f) => {
+            let value = f(values)?;
+            return Ok(value);
+        }
+        Value::HostFn(f) => {
+            let value = f(scopes, values, prototypes)?;
+            return Ok(value);
+        }
+        Value::Func(params, ret_type, block, captured) => {
+            let variadic = params.iter().any(|param| param.rest);
+            if !variadic && values.len() > params.len() {
+                return Err(format!(
+                    "expected {} arguments but found {}",
+                    params.len(),
+                    values.len()
+                ));
+            }
+
+            // the body sees the scopes the function was defined in, not the caller's
+            let mut inner_scope = captured.0.new_from_push(HashMap::new());
+            let mut values = values.into_iter();
+            for param in params.iter() {
+                if param.rest {
+                    let item_type = inner_scope.get_type_alias(&param.datatype)?;
+                    let items: Vec<Value> = values.by_ref().collect();
+
+                    for item in &items {
+                        if Type::from(item) != item_type {
+                            return Err(format!(
+                                "expected `{}` found `{}` for parameter '{}'",
+                                item_type,
+                                Type::from(item),
+                                param.ident
+                            ));
+                        }
+                    }
+
+                    inner_scope.declare(
+                        &param.ident,
+                        Value::List(items),
+                        &None,
+                        DeclType::Mutable,
+                    )?;
+                    continue;
+                }
+
+                // defaults are evaluated on each call and can use the earlier params
+                let value = match (values.next(), &param.default) {
+                    (Some(value), _) => value,
+                    (None, Some(default)) => {
+                        eval_expression(&mut inner_scope, default, prototypes)?
+                    }
+                    (None, None) => {
+                        return Err(format!("missing argument for parameter '{}'", param.ident))
+                    }
+                };
+
+                inner_scope.declare(
+                    &param.ident,
+                    value,
+                    &Some(param.datatype.clone()),
+                    DeclType::Mutable,
+                )?;
+            }
+
+            let ret = eval_statements(&mut inner_scope, &block, prototypes)?;
+            let value = match ret {
+                Escape::None => Value::Null,
+                Escape::Return(value) => value,
+                Escape::Break => return Err(format!("break outside of loop (2)")),
+                Escape::Continue => return Err(format!("continue out side of loop (2)")),
+            };
+
+            if let Some(ret_type) = ret_type {
+                let ret_type = inner_scope.get_type_alias(&ret_type)?;
+                if Type::from(&value) != ret_type {
+                    return Err(format!(
+                        "expected `{}` found `{}` (13)",
+                        ret_type,
+                        Type::from(&value)
+                    ));
+                }
+            }
+
+            Ok(value)
+        }
+        Value::BuiltInMethod(f, this) => {
+            if let Some(this) = this {
+                let res = f(values, *this)?;
+                return Ok(res);
+            } else {
+                return Err("dev error".to_string());
+            }
+        }
+        _ => {
+            return Err(format!(
+                "value of type '{:?}' is not callable (5)",
+                String::from(Type::from(&value))
+            ));
+        }
+    }
+}
+
+pub fn eval_ident_expr(scopes: &mut ScopeStack, name: &String) -> Result<Value, String> {
+    match scopes.get(&name) {
+        Some(v) => Ok(v),
+        None => Err(format!("{} is not defied (8)", name)),
+    }
+}
+
+pub fn eval_method_call_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    object: &Box<Expr>,
+    calle: &Box<Expr>,
+) -> Result<Value, String> {
+    let obj_value = eval_expression(scopes, &*object, &prototypes)?;
+
+    match *calle.clone() {
+        Expr::Identifier(name) => match &obj_value {
+            Value::Object(props) => match props.iter().find(|kv| kv.key == name) {
+                Some(kv) => return Ok(kv.value.clone()),
+                None => return Err(format!("property '{}' does not exist in object", name)),
+            },
+            Value::Module(items) => match items.get(&name) {
+                Some(value) => return Ok(value.clone()),
+                None => return Err(format!("'{}' does not exist in module", name)),
+            },
+            _ => match prototypes.get(&Type::simple(&obj_value.clone())) {
+                Some(proto) => match proto.get(&name) {
+                    Some(value) => {
+                        if let Value::BuiltInMethod(f, _) = value {
+                            return Ok(Value::BuiltInMethod(*f, Some(Box::new(obj_value))));
+                        }
+                        return Ok(value.to_owned());
+                    }
+                    None => {
+                        return Err(format!(
+                            "type {} has no method '{}'",
+                            Type::simple(&obj_value),
+                            name
+                        ));
+                    }
+                },
+                None => {
+                    return Err(format!(
+                        "type {} has no method '{}'",
+                        Type::simple(&obj_value),
+                        name
+                    ));
+                }
+            },
+        },
+        Expr::Call(expr, args) => match *expr {
+            Expr::Identifier(name) => {
+                let proto = prototypes.get(&Type::simple(&obj_value));
+
+                let method = match (proto.and_then(|proto| proto.get(&name)), &obj_value) {
+                    (Some(Value::BuiltInMethod(f, _)), _) => {
+                        Value::BuiltInMethod(*f, Some(Box::new(obj_value.clone())))
+                    }
+                    (Some(value), _) => value.clone(),
+                    (None, Value::Object(props)) => match props.iter().find(|kv| kv.key == name) {
+                        Some(kv) => kv.value.clone(),
+                        None => {
+                            return Err(format!("property '{}' does not exist in object", name))
+                        }
+                    },
+                    (None, Value::Module(items)) => match items.get(&name) {
+                        Some(value) => value.clone(),
+                        None => return Err(format!("'{}' does not exist in module", name)),
+                    },
+                    (None, _) => {
+                        return Err(format!(
+                            "type {} has no method '{}'",
+                            Type::simple(&obj_value),
+                            name
+                        ))
+                    }
+                };
+
+                let values = eval_call_args(scopes, prototypes, &args)?;
+                return call_value(scopes, prototypes, method, values);
+            }
+            _ => {
+                return Err(format!(
+                    "value of type {:?} not callable (2)",
+                    String::from(Type::from(&obj_value))
+                ));
+            }
+        },
+        Expr::Int(n) => {
+            return Err(format!(
+                "{} is not found in {} prototype",
+                n,
+                String::from(Type::from(&obj_value))
+            ))
+        }
+
+        _ => {
+            return Err(format!(
+                "value of type {:?} not callable (1)",
+                String::from(Type::from(&obj_value))
+            ));
+        }
+    }
+}
+
+pub fn eval_index_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    expr: &Box<Expr>,
+    loc: &Box<Expr>,
+) -> Result<Value, String> {
+    let expr_value = eval_expression(scopes, &*expr, &prototypes)?;
+
+    match &expr_value {
+        Value::String(s) => {
+            let loc_value = eval_expression(scopes, &*loc, &prototypes)?;
+
+            match loc_value {
+                Value::Int(index) => {
+                    let index = resolve_index(&expr_value, index, s.chars().count())?;
+                    return Ok(Value::String(s.chars().nth(index).unwrap().to_string()));
+                }
+                _ => {
+                    return Err(format!(
+                        "the type {:?} cannot be indexed by {:?}",
+                        String::from(Type::from(&expr_value)),
+                        String::from(Type::from(&loc_value))
+                    ))
+                }
+            }
+        }
+        Value::List(l) | Value::Tuple(l) => {
+            let loc_value = eval_expression(scopes, &*loc, &prototypes)?;
+
+            match loc_value {
+                Value::Int(index) => {
+                    let index = resolve_index(&expr_value, index, l.len())?;
+                    return Ok(Value::from(&l[index]));
+                }
+                _ => {
+                    return Err(format!(
+                        "the type {:?} cannot be indexed by {:?}",
+                        String::from(Type::from(&expr_value)),
+                        String::from(Type::from(&loc_value))
+                    ))
+                }
+            }
+        }
+        _ => {
+            return Err(format!(
+                "cannot index into a value of type {:?}",
+                String::from(Type::from(&expr_value)),
+            ));
+        }
+    }
+}
+
+// negative indexes count from the end, `-1` is the last item
+fn resolve_index(value: &Value, index: i32, len: usize) -> Result<usize, String> {
+    let resolved = if index < 0 {
+        len as i64 + index as i64
+    } else {
+        index as i64
+    };
+
+    if resolved < 0 || resolved >= len as i64 {
+        return Err(format!(
+            "index {} is out of range for a {} of length {}",
+            index,
+            Type::simple(value),
+            len
+        ));
+    }
+
+    Ok(resolved as usize)
+}
+
+pub fn eval_binary_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    lhs: &Box<Expr>,
+    op: &BinaryOpKind,
+    rhs: &Box<Expr>,
+) -> Result<Value, String> {
+    let lhs = eval_expression(scopes, &*lhs, &prototypes)?;
+
+    // the rhs of `&&` and `||` is only evaluated when the lhs doesn't decide the result
+    if let BinaryOpKind::And | BinaryOpKind::Or = op {
+        let value = match (op, &lhs) {
+            (BinaryOpKind::And, Value::Bool(false)) => return Ok(Value::Bool(false)),
+            (BinaryOpKind::Or, Value::Bool(true)) => return Ok(Value::Bool(true)),
+            (_, Value::Bool(_)) => eval_expression(scopes, &*rhs, &prototypes)?,
+            _ => {
+                return Err(format!(
+                    "expected bool found {:?}",
+                    String::from(Type::from(&lhs))
+                ))
+            }
+        };
+
+        return match value {
+            Value::Bool(_) => Ok(value),
+            _ => Err(format!(
+                "expected bool found {:?}",
+                String::from(Type::from(&value))
+            )),
+        };
+    }
+
+    let rhs = eval_expression(scopes, &*rhs, &prototypes)?;
+
+    let res = match op {
+        BinaryOpKind::Add => &lhs + &rhs,
+        BinaryOpKind::Sub => &lhs - &rhs,
+        BinaryOpKind::Mul => &lhs * &rhs,
+        BinaryOpKind::Div => &lhs / &rhs,
+        BinaryOpKind::EQ => Ok(Value::Bool(structural_eq(&lhs, &rhs))),
+        BinaryOpKind::NE => Ok(Value::Bool(!structural_eq(&lhs, &rhs))),
+        BinaryOpKind::GT => Ok(Value::Bool(compare(&lhs, &rhs)? == Some(Ordering::Greater))),
+        BinaryOpKind::LT => Ok(Value::Bool(compare(&lhs, &rhs)? == Some(Ordering::Less))),
+        BinaryOpKind::GTE => Ok(Value::Bool(matches!(
+            compare(&lhs, &rhs)?,
+            Some(Ordering::Greater | Ordering::Equal)
+        ))),
+        BinaryOpKind::LTE => Ok(Value::Bool(matches!(
+            compare(&lhs, &rhs)?,
+            Some(Ordering::Less | Ordering::Equal)
+        ))),
+        BinaryOpKind::And | BinaryOpKind::Or => unreachable!(),
+    };
+
+    res
+}
+
+pub fn eval_unary_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    op: &UnaryOpKind,
+    expr: &Box<Expr>,
+) -> Result<Value, String> {
+    let value = eval_expression(scopes, &*expr, prototypes)?;
+
+    match op {
+        UnaryOpKind::Not => !value,
+        UnaryOpKind::Neg => -value,
+        UnaryOpKind::Typeof => Ok(Value::String(Type::simple(&value))),
+    }
+}
+
+// embedded values are rendered the same way `print` renders them
+pub fn eval_interpolation_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    parts: &Vec<StringPart>,
+) -> Result<Value, String> {
+    let mut res = String::new();
+
+    for part in parts {
+        match part {
+            StringPart::Text(text) => res.push_str(text),
+            StringPart::Expr(expr) => {
+                let value = eval_expression(scopes, expr, &prototypes)?;
+                res.push_str(&value.to_string());
+            }
+        }
+    }
+
+    Ok(Value::String(res))
+}
+
+pub fn eval_object_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    props: &Vec<Prop>,
+) -> Result<Value, String> {
+    let mut values: Vec<KeyValue> = Vec::new();
+
+    for prop in props {
+        if values.iter().any(|kv| kv.key == prop.key) {
+            return Err(format!("duplicate property '{}'", prop.key));
+        }
+
+        let value = eval_expression(scopes, &prop.value, &prototypes)?;
+
+        values.push(KeyValue {
+            key: prop.key.to_string(),
+            value,
+        });
+    }
+
+    Ok(Value::Object(values))
+}
+
+// the function keeps the scopes it was created in, see `Captured`
+pub fn eval_fn_expr(
+    scopes: &mut ScopeStack,
+    args: &Vec<Arg>,
+    ret_type: &Option<Type>,
+    block: &Vec<Statement>,
+) -> Result<Value, String> {
+    let captured = Captured(scopes.clone());
+    Ok(Value::Func(
+        args.to_vec(),
+        ret_type.clone(),
+        block.to_vec(),
+        captured,
+    ))
+}
+
+pub fn eval_module_call_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    paths: &Vec<String>,
+    expr: &Box<Expr>,
+) -> Result<Value, String> {
+    let module = get_module(scopes, paths)?;
+
+    let mut inner_scopes = scopes.new_from_push(HashMap::new());
+
+    for (key, value) in module {
+        inner_scopes.import(&key, value)?;
+    }
+
+    let value = eval_expression(&mut inner_scopes, &*expr, &prototypes)?;
+    Ok(value)
+}
+
+pub fn eval_module_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    statements: &Vec<Statement>,
+) -> Result<Value, String> {
+    let module = eval_module(scopes, prototypes, &String::from("test"), statements)?;
+    Ok(Value::Module(module))
+}
+
+pub fn eval_if_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    branchs: &Vec<Branch>,
+    else_block: &Option<Vec<Statement>>,
+) -> Result<Value, String> {
+    for branch in branchs {
+        let value = eval_expression(scopes, &branch.condition, &prototypes)?;
+
+        match value {
+            Value::Bool(b) => {
+                if b {
+                    let ret = eval_statements(scopes, &branch.statements, &prototypes)?;
+
+                    if let Escape::Return(value) = ret {
+                        return Ok(value);
+                    }
+
+                    return Ok(Value::Null);
+                }
+            }
+            _ => return Err(format!("condition most be a boolean")),
+        }
+    }
+
+    if let Some(stmts) = else_block {
+        let e = eval_statements(scopes, stmts, &prototypes)?;
+
+        if let Escape::Return(value) = e {
+            return Ok(value);
+        }
+    }
+
+    Ok(Value::Null)
+}
+
+// only the taken branch is evaluated
+pub fn eval_conditional_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    cond: &Box<Expr>,
+    then: &Box<Expr>,
+    otherwise: &Box<Expr>,
+) -> Result<Value, String> {
+    match eval_expression(scopes, &*cond, &prototypes)? {
+        Value::Bool(true) => eval_expression(scopes, &*then, &prototypes),
+        Value::Bool(false) => eval_expression(scopes, &*otherwise, &prototypes),
+        _ => return Err(format!("condition most be a boolean")),
+    }
+}
+
+pub fn eval_tuple_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    exprs: &Vec<Expr>,
+) -> Result<Value, String> {
+    let mut values = Vec::new();
+
+    for expr in exprs {
+        let value = eval_expression(scopes, expr, &prototypes)?;
+        values.push(value);
+    }
+
+    Ok(Value::Tuple(values))
+}
+
+pub fn eval_range_expr(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    start: &Box<Expr>,
+    end: &Box<Expr>,
+    inclusive: &bool,
+    step: &Option<Box<Expr>>,
+) -> Result<Value, String> {
+    let range = eval_range(scopes, prototypes, start, end, inclusive, step)?;
+
+    Ok(Value::List(range.map(Value::Int).collect()))
+}
+
+// counts from `start` to `end` without building a list, `end` is only reached when `inclusive`.
+// the step defaults to 1, so a descending range is empty unless a negative step is given
+pub fn eval_range(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    start: &Box<Expr>,
+    end: &Box<Expr>,
+    inclusive: &bool,
+    step: &Option<Box<Expr>>,
+) -> Result<impl Iterator<Item = i32>, String> {
+    let start = range_bound(eval_expression(scopes, &start, prototypes)?)?;
+    let end = range_bound(eval_expression(scopes, &end, prototypes)?)?;
+    let step = match step {
+        Some(step) => range_bound(eval_expression(scopes, &step, prototypes)?)?,
+        None => 1,
+    };
+
+    if step == 0 {
+        return Err(format!("range step cannot be zero"));
+    }
+
+    // i64 so stepping past `i32::MAX` or `i32::MIN` ends the range instead of overflowing
+    let mut next = start as i64;
+    let (end, step, inclusive) = (end as i64, step as i64, *inclusive);
+
+    Ok(std::iter::from_fn(move || {
+        let done = match (step > 0, inclusive) {
+            (true, true) => next > end,
+            (true, false) => next >= end,
+            (false, true) => next < end,
+            (false, false) => next <= end,
+        };
+
+        if done {
+            return None;
+        }
+
+        let num = next as i32;
+        next += step;
+        Some(num)
+    }))
+}
+
+fn range_bound(value: Value) -> Result<i32, String> {
+    match value {
+        Value::Int(n) => Ok(n),
+        other => Err(format!(
+            "expected integer, found {}",
+            String::from(Type::from(&other))
+        )),
+    }
+}
+
+pub fn get_module(
+    scopes: &mut ScopeStack,
+    paths: &Vec<String>,
+) -> Result<BTreeMap<String, Value>, String> {
+    let mut exports: BTreeMap<String, Value> = BTreeMap::new();
+
+    for path in paths {
+        match exports.get(path) {
+            Some(value) => match value.to_owned() {
+                Value::Module(items) => {
+                    exports = items;
+                }
+                _ => return Err(format!("module {} not found (1)", path)),
+            },
+            None => match scopes.get(path) {
+                Some(value) => match value {
+                    Value::Module(items) => {
+                        exports = items;
+                    }
+                    _ => return Err(format!("module {} not found (2)", path)),
+                },
+                None => {
+                    let mut path = String::new();
+                    path.push_str("./examples/");
+                    path.push_str(&paths.join("/"));
+                    path.push_str(".ak");
+
+                    let file_result = fs::read_to_string(path);
+
+                    if let Ok(file) = file_result {
+                        let file = strip_block_comments(&file)?;
+                        let program = grammar::programParser::new().parse(&file).expect(&format!(
+                            "unable to compile module {}",
+                            paths.last().unwrap()
+                        ));
+                        eval_program_and_push_scope(scopes, program, &Prototypes::exports())?;
+                        break;
+                    } else {
+                        return Err(format!("module {} not found (3)", paths.last().unwrap()));
+                    }
+                }
+            },
+        }
+    }
+
+    Ok(exports)
+}
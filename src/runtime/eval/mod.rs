@@ -1,5 +1,5 @@
-pub mod expression;
-pub mod program;
-pub mod statement;
-
-pub use program::eval_program;
+pub mod expression;
+pub mod program;
+pub mod statement;
+
+pub use program::eval_program;
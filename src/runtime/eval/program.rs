@@ -1,51 +1,199 @@
-use std::collections::HashMap;
-
-use crate::ast::Program;
-use crate::runtime::value::Value;
-use crate::runtime::ScopeStack;
-
-use super::statement::{eval_statements, eval_statements_and_push_scope, Escape};
-
-pub fn eval_program(
-    scopes: &mut ScopeStack,
-    program: Program,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-) -> Result<Escape, String> {
-    let e = eval_statements(scopes, &program.statements, prototypes)?;
-
-    if let Escape::Return(_) = e {
-        return Err(format!("return outside of function"));
-    }
-
-    if let Escape::Break = e {
-        return Err(format!("break outside of loop"));
-    }
-
-    if let Escape::Continue = e {
-        return Err(format!("continue outside of loop"));
-    }
-
-    Ok(e)
-}
-
-pub fn eval_program_and_push_scope(
-    scopes: &mut ScopeStack,
-    program: Program,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-) -> Result<Escape, String> {
-    let e = eval_statements_and_push_scope(scopes, &program.statements, prototypes)?;
-
-    if let Escape::Return(_) = e {
-        return Err(format!("return outside of function"));
-    }
-
-    if let Escape::Break = e {
-        return Err(format!("break outside of loop"));
-    }
-
-    if let Escape::Continue = e {
-        return Err(format!("continue outside of loop"));
-    }
-
-    Ok(e)
-}
+use std::collections::HashMap;
+
+use crate::ast::Program;
+use crate::runtime::value::Value;
+use crate::runtime::ScopeStack;
+
+use super::statement::{eval_statements, eval_statements_and_push_scope, Escape};
+
+pub fn eval_program(
+    scopes: &mut ScopeStack,
+    program: Program,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+) -> Result<Escape, String> {
+    let e = eval_statements(scopes, &program.statements, prototypes)?;
+
+    if let Escape::Return(_) = e {
+        return Err(format!("return outside of function"));
+    }
+
+    if let Escape::Break = e {
+        return Err(format!("break outside of loop"));
+    }
+
+    if let Escape::Continue = e {
+        return Err(format!("continue outside of loop"));
+    }
+
+    Ok(e)
+}
+
+pub fn eval_program_and_push_scope(
+    scopes: &mut ScopeStack,
+    program: Program,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+) -> Result<Escape, String> {
+    let e = eval_statements_and_push_scope(scopes, &program.statements, prototypes)?;
+
+    if let Escape::Return(_) = e {
+        return Err(format!("return outside of function"));
+    }
+
+    if let Escape::Break = e {
+        return Err(format!("break outside of loop"));
+    }
+
+    if let Escape::Continue = e {
+        return Err(format!("continue outside of loop"));
+    }
+
+    Ok(e)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::eval_program_and_push_scope;
+    use crate::grammar::programParser;
+    use crate::runtime::value::Value;
+    use crate::runtime::{Prototypes, ScopeStack, StdLib};
+
+    fn run(code: &str) -> Result<ScopeStack, String> {
+        let mut scopes = ScopeStack::new(vec![Arc::new(Mutex::new(StdLib::exports()))]);
+        let ast = programParser::new()
+            .parse(code)
+            .map_err(|e| e.to_string())?;
+
+        eval_program_and_push_scope(&mut scopes, ast, &Prototypes::exports())?;
+        Ok(scopes)
+    }
+
+    #[test]
+    fn declaring_a_function_does_not_run_its_body() {
+        let scopes = run("
+            let calls = 0;
+            let f = fn(x: int) { calls = calls + 1; };
+            const g = fn() { calls = calls + 1; return calls; };
+        ")
+        .unwrap();
+
+        assert_eq!(scopes.get(&"calls".to_string()), Some(Value::Int(0)));
+    }
+
+    #[test]
+    fn unannotated_function_binds_to_typed_variable() {
+        let scopes = run("
+            let f: fn(int) -> int = fn(x: int) { return x * 2; };
+            let res = f(4);
+        ")
+        .unwrap();
+
+        assert_eq!(scopes.get(&"res".to_string()), Some(Value::Int(8)));
+        assert_eq!(
+            run("let f: fn(int) -> int = fn(x: int) { return \"a\"; }; f(1);").err(),
+            Some("expected `int` found `string` (13)".to_string())
+        );
+        assert!(run("let f: fn(int) -> int = fn(x: string) { return 1; };").is_err());
+    }
+
+    #[test]
+    fn returned_closure_keeps_its_own_local() {
+        let scopes = run("
+            fn make_counter() {
+                let count = 0;
+                return fn() { count = count + 1; return count; };
+            }
+            let counter = make_counter();
+            let first = counter();
+            let second = counter();
+        ")
+        .unwrap();
+
+        assert_eq!(scopes.get(&"first".to_string()), Some(Value::Int(1)));
+        assert_eq!(scopes.get(&"second".to_string()), Some(Value::Int(2)));
+        assert_eq!(scopes.get(&"count".to_string()), None);
+    }
+
+    #[test]
+    fn counters_from_one_factory_keep_separate_state() {
+        let scopes = run("
+            fn make_counter() {
+                let count = 0;
+                return fn() { count = count + 1; return count; };
+            }
+            let a = make_counter();
+            let b = make_counter();
+            a();
+            a();
+            let a_count = a();
+            let b_count = b();
+        ")
+        .unwrap();
+
+        assert_eq!(scopes.get(&"a_count".to_string()), Some(Value::Int(3)));
+        assert_eq!(scopes.get(&"b_count".to_string()), Some(Value::Int(1)));
+    }
+
+    #[test]
+    fn object_property_is_called_as_method() {
+        let scopes = run("
+            let o = { double: fn(x: int) -> int { return x * 2; }, name: \"o\" };
+            let res = o.double(4);
+        ")
+        .unwrap();
+
+        assert_eq!(scopes.get(&"res".to_string()), Some(Value::Int(8)));
+        assert!(run("let o = { name: \"o\" }; o.name();").is_err());
+    }
+
+    fn list(nums: &[i32]) -> Option<Value> {
+        Some(Value::List(nums.iter().map(|n| Value::Int(*n)).collect()))
+    }
+
+    #[test]
+    fn range_ends() {
+        let scopes = run("
+            let half_open = 1..4;
+            let inclusive = 1..=4;
+            let descending = 4..1;
+            let descending_inclusive = 4..=1;
+            let descending_step = 4..1 step -1;
+            let descending_inclusive_step = 4..=1 step -1;
+            let half_open_sum = 0;
+            for i in 1..4 { half_open_sum = half_open_sum + i; }
+            let inclusive_sum = 0;
+            for i in 1..=4 { inclusive_sum = inclusive_sum + i; }
+        ")
+        .unwrap();
+
+        assert_eq!(scopes.get(&"half_open".to_string()), list(&[1, 2, 3]));
+        assert_eq!(scopes.get(&"inclusive".to_string()), list(&[1, 2, 3, 4]));
+        assert_eq!(scopes.get(&"descending".to_string()), list(&[]));
+        assert_eq!(scopes.get(&"descending_inclusive".to_string()), list(&[]));
+        assert_eq!(scopes.get(&"descending_step".to_string()), list(&[4, 3, 2]));
+        assert_eq!(
+            scopes.get(&"descending_inclusive_step".to_string()),
+            list(&[4, 3, 2, 1])
+        );
+        assert_eq!(
+            scopes.get(&"half_open_sum".to_string()),
+            Some(Value::Int(6))
+        );
+        assert_eq!(
+            scopes.get(&"inclusive_sum".to_string()),
+            Some(Value::Int(10))
+        );
+    }
+
+    #[test]
+    fn step_is_still_a_name() {
+        let scopes = run("
+            let step = 2;
+            let evens = 0..=6 step step;
+        ")
+        .unwrap();
+
+        assert_eq!(scopes.get(&"evens".to_string()), list(&[0, 2, 4, 6]));
+    }
+}
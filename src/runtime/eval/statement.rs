@@ -1,303 +1,338 @@
-use std::collections::{BTreeMap, HashMap};
-
-use crate::ast::Statement;
-use crate::runtime::value::{BuiltinType, Value};
-use crate::runtime::{DeclType, ScopeStack, Simple, Type};
-
-use super::expression::{eval_expression, get_module};
-
-#[derive(Debug, Clone)]
-pub enum Escape {
-    None,
-    Return(Value),
-    Break,
-    Continue,
-}
-
-pub fn eval_statement(
-    scopes: &mut ScopeStack,
-    statement: &Statement,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-) -> Result<Escape, String> {
-    match statement {
-        Statement::Expression(expr) => {
-            eval_expression(scopes, expr, prototypes)?;
-        }
-        Statement::Let(name, datatype, rhs) => {
-            let value = eval_expression(scopes, rhs, prototypes)?;
-
-            if let Some(datatype) = datatype {
-                scopes.declare_variable(name, datatype, &value, DeclType::Mutable)?;
-            } else {
-                scopes.declare_variable(name, &Type::from(&value), &value, DeclType::Mutable)?;
-            }
-        }
-        Statement::Const(name, datatype, rhs) => {
-            let value = eval_expression(scopes, rhs, prototypes)?;
-
-            if let Some(datatype) = datatype {
-                scopes.declare_variable(name, datatype, &value, DeclType::Immutable)?;
-            } else {
-                scopes.declare_variable(name, &Type::from(&value), &value, DeclType::Immutable)?;
-            }
-        }
-        Statement::Import(args, items) => {
-            let module = get_module(scopes, args)?;
-
-            match items {
-                Some(list) => {
-                    for (key, value) in module {
-                        if list.contains(&key) {
-                            scopes.declare(
-                                &key,
-                                value.clone(),
-                                &Some(Type::from(&value)),
-                                DeclType::Immutable,
-                            )?;
-                        }
-                    }
-                }
-                None => {
-                    if let Some(m) = args.last() {
-                        scopes.declare(
-                            m,
-                            Value::Module(module),
-                            &Some(Type::Alias("todo".to_string())),
-                            DeclType::Immutable,
-                        )?;
-                    }
-                }
-            }
-        }
-        Statement::Assignment(name, rhs) => {
-            let value = eval_expression(scopes, rhs, prototypes)?;
-            scopes.assgin(name.to_string(), value)?;
-        }
-        Statement::If(branchs, else_block) => {
-            for branch in branchs {
-                let value = eval_expression(scopes, &branch.condition, prototypes)?;
-
-                match value {
-                    Value::Bool(b) => {
-                        if b {
-                            let ret = eval_statements(scopes, &branch.statements, prototypes)?;
-                            return Ok(ret);
-                        }
-                    }
-                    _ => return Err(format!("condition most be a boolean")),
-                }
-            }
-
-            if let Some(stmts) = else_block {
-                let e = eval_statements(scopes, stmts, prototypes)?;
-                return Ok(e);
-            }
-        }
-        Statement::Return(expr) => {
-            let value = eval_expression(scopes, expr, prototypes)?;
-            return Ok(Escape::Return(value));
-        }
-        Statement::Fn(name, args, ret_type, block) => {
-            if let Some(ret_type) = ret_type {
-                scopes.declare_fn_statement(name, args, ret_type, block)?;
-            } else {
-                scopes.declare_fn_statement(
-                    name,
-                    args,
-                    &Type::Builtin(BuiltinType::Null),
-                    block,
-                )?;
-            }
-        }
-        Statement::For(lhs, iter, block) => {
-            let iter_val = eval_expression(scopes, iter, prototypes)?;
-
-            match &iter_val {
-                Value::List(values) | Value::Tuple(values) => {
-                    for (i, value) in values.iter().enumerate() {
-                        let mut inner_scopes = scopes.new_from_push(HashMap::new());
-
-                        inner_scopes.declare(
-                            lhs,
-                            value.clone(),
-                            &Some(Type::from(value)),
-                            DeclType::Mutable,
-                        )?;
-
-                        inner_scopes.declare(
-                            &"index".to_string(),
-                            Value::Int(i as i32),
-                            &Some(Type::Builtin(BuiltinType::Int)),
-                            DeclType::Immutable,
-                        )?;
-
-                        let ret = eval_statements(&mut inner_scopes, block, prototypes)?;
-
-                        match ret {
-                            Escape::None => {}
-                            Escape::Continue => {}
-                            Escape::Return(v) => return Ok(Escape::Return(v)),
-                            Escape::Break => return Ok(Escape::None),
-                        }
-                    }
-                }
-                _ => {
-                    return Err(format!(
-                        "iterator most be a list, found {}",
-                        Type::simple(&iter_val)
-                    ))
-                }
-            }
-        }
-        Statement::Break => return Ok(Escape::Break),
-        Statement::Continue => return Ok(Escape::Continue),
-        Statement::While(cond, block) => loop {
-            let value = eval_expression(scopes, cond, prototypes)?;
-
-            match value {
-                Value::Bool(b) => {
-                    if !b {
-                        break;
-                    }
-
-                    let ret = eval_statements(scopes, block, prototypes)?;
-
-                    match ret {
-                        Escape::None => {}
-                        Escape::Continue => {}
-                        Escape::Return(v) => return Ok(Escape::Return(v)),
-                        Escape::Break => return Ok(Escape::None),
-                    }
-                }
-                _ => return Err(format!("condition most be a boolean")),
-            }
-        },
-        Statement::Module(name, statements) => {
-            let module = eval_module(scopes, prototypes, name, statements)?;
-
-            scopes.declare(
-                name,
-                Value::Module(module),
-                &Some(Type::Alias("todo".to_string())),
-                DeclType::Immutable,
-            )?;
-        }
-        Statement::Type(name, datatype) => {
-            scopes.declare_type_alias(name, datatype)?;
-        }
-    };
-
-    Ok(Escape::None)
-}
-
-pub fn eval_statements(
-    scopes: &mut ScopeStack,
-    statements: &Vec<Statement>,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-) -> Result<Escape, String> {
-    let mut inner_scopes = scopes.new_from_push(HashMap::new());
-
-    for statement in statements {
-        let e = eval_statement(&mut inner_scopes, statement, prototypes)?;
-
-        if let Statement::Fn(..) = statement {
-            continue;
-        }
-
-        if let Escape::None = e {
-            continue;
-        }
-
-        return Ok(e);
-    }
-
-    Ok(Escape::None)
-}
-
-pub fn eval_statements_and_push_scope(
-    scopes: &mut ScopeStack,
-    statements: &Vec<Statement>,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-) -> Result<Escape, String> {
-    scopes.push(HashMap::new());
-
-    for statement in statements {
-        let e = eval_statement(scopes, statement, prototypes)?;
-
-        if let Statement::Fn(..) = statement {
-            continue;
-        }
-
-        if let Escape::None = e {
-            continue;
-        }
-
-        return Ok(e);
-    }
-
-    Ok(Escape::None)
-}
-
-pub fn eval_module(
-    scopes: &mut ScopeStack,
-    prototypes: &HashMap<String, HashMap<String, Value>>,
-    name: &String,
-    statements: &Vec<Statement>,
-) -> Result<BTreeMap<String, Value>, String> {
-    let mut exports: BTreeMap<String, Value> = BTreeMap::new();
-
-    let mut inner_scope = scopes.new_from_push(HashMap::new());
-    for statement in statements {
-        match statement {
-            Statement::Const(name, datatype, expr) => {
-                let value = eval_expression(&mut inner_scope, expr, prototypes)?;
-
-                // type checking
-                if let Some(datatype) = datatype {
-                    if &Type::from(&value) != datatype {
-                        return Err(format!(
-                            "expected {} found {}",
-                            datatype,
-                            Type::from(&value),
-                        ));
-                    }
-                }
-                exports.insert(name.to_string(), value);
-            }
-            Statement::Let(name, datatype, expr) => {
-                let value = eval_expression(&mut inner_scope, expr, prototypes)?;
-
-                // type checking
-                if let Some(datatype) = datatype {
-                    if &Type::from(&value) != datatype {
-                        return Err(format!(
-                            "expected {} found {}",
-                            datatype,
-                            Type::from(&value),
-                        ));
-                    }
-                }
-                exports.insert(name.to_string(), value);
-            }
-            Statement::Fn(name, args, ret_type, block) => {
-                exports.insert(
-                    name.to_string(),
-                    Value::Func(args.to_vec(), ret_type.clone(), block.to_vec()),
-                );
-            }
-            Statement::Module(name2, statements2) => {
-                let exports2 = eval_module(&mut inner_scope, prototypes, name2, statements2)?;
-                exports.insert(name2.to_string(), Value::Module(exports2));
-            }
-            other => return Err(format!("'{:?}' is not supported in modules", other)),
-        }
-    }
-
-    inner_scope.declare(
-        name,
-        Value::Module(exports.clone()),
-        &Some(Type::Alias("module".to_string())),
-        DeclType::Immutable,
-    )?;
-    Ok(exports)
-}
+use std::collections::{BTreeMap, HashMap};
+
+use crate::ast::{Expr, Statement};
+use crate::runtime::value::{structural_eq, BuiltinType, Captured, Value};
+use crate::runtime::{DeclType, ScopeStack, Simple, Type};
+
+use super::expression::{eval_expression, eval_range, get_module};
+
+#[derive(Debug, Clone)]
+pub enum Escape {
+    None,
+    Return(Value),
+    Break,
+    Continue,
+}
+
+pub fn eval_statement(
+    scopes: &mut ScopeStack,
+    statement: &Statement,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+) -> Result<Escape, String> {
+    match statement {
+        Statement::Expression(expr) => {
+            eval_expression(scopes, expr, prototypes)?;
+        }
+        Statement::Let(name, datatype, rhs) => {
+            let value = eval_expression(scopes, rhs, prototypes)?;
+
+            if let Some(datatype) = datatype {
+                scopes.declare_variable(name, datatype, &value, DeclType::Mutable)?;
+            } else {
+                scopes.declare_variable(name, &Type::from(&value), &value, DeclType::Mutable)?;
+            }
+        }
+        Statement::Const(name, datatype, rhs) => {
+            let value = eval_expression(scopes, rhs, prototypes)?;
+
+            if let Some(datatype) = datatype {
+                scopes.declare_variable(name, datatype, &value, DeclType::Immutable)?;
+            } else {
+                scopes.declare_variable(name, &Type::from(&value), &value, DeclType::Immutable)?;
+            }
+        }
+        Statement::Import(args, items) => {
+            let module = get_module(scopes, args)?;
+
+            match items {
+                Some(list) => {
+                    for (key, value) in module {
+                        if list.contains(&key) {
+                            scopes.import(&key, value)?;
+                        }
+                    }
+                }
+                None => {
+                    if let Some(m) = args.last() {
+                        scopes.import(m, Value::Module(module))?;
+                    }
+                }
+            }
+        }
+        Statement::Assignment(name, rhs) => {
+            let value = eval_expression(scopes, rhs, prototypes)?;
+            scopes.assgin(name.to_string(), value)?;
+        }
+        Statement::If(branchs, else_block) => {
+            for branch in branchs {
+                let value = eval_expression(scopes, &branch.condition, prototypes)?;
+
+                match value {
+                    Value::Bool(b) => {
+                        if b {
+                            let ret = eval_statements(scopes, &branch.statements, prototypes)?;
+                            return Ok(ret);
+                        }
+                    }
+                    _ => return Err(format!("condition most be a boolean")),
+                }
+            }
+
+            if let Some(stmts) = else_block {
+                let e = eval_statements(scopes, stmts, prototypes)?;
+                return Ok(e);
+            }
+        }
+        Statement::Match(value, arms, default) => {
+            // the value is evaluated once, then compared with each pattern in order
+            let value = eval_expression(scopes, value, prototypes)?;
+
+            for arm in arms {
+                let pattern = eval_expression(scopes, &arm.pattern, prototypes)?;
+
+                if structural_eq(&value, &pattern) {
+                    return eval_statements(scopes, &arm.statements, prototypes);
+                }
+            }
+
+            if let Some(stmts) = default {
+                return eval_statements(scopes, stmts, prototypes);
+            }
+        }
+        Statement::Try(try_block, name, catch_block) => {
+            // `return`, `break` and `continue` pass through, only errors are caught
+            match eval_statements(scopes, try_block, prototypes) {
+                Ok(e) => return Ok(e),
+                Err(message) => {
+                    let mut inner_scopes = scopes.new_from_push(HashMap::new());
+                    inner_scopes.declare(name, Value::String(message), &None, DeclType::Mutable)?;
+
+                    return eval_statements(&mut inner_scopes, catch_block, prototypes);
+                }
+            }
+        }
+        Statement::Return(expr) => {
+            let value = eval_expression(scopes, expr, prototypes)?;
+            return Ok(Escape::Return(value));
+        }
+        Statement::Fn(name, args, ret_type, block) => {
+            scopes.declare_fn_statement(name, args, ret_type, block)?;
+        }
+        Statement::For(lhs, iter, block) => {
+            // ranges are counted through directly instead of building the list first
+            if let Expr::Range(start, end, inclusive, step) = iter {
+                let range = eval_range(scopes, prototypes, start, end, inclusive, step)?;
+
+                for (i, num) in range.enumerate() {
+                    let value = Value::Int(num);
+                    let ret = eval_for_body(scopes, prototypes, lhs, i, value, block)?;
+
+                    match ret {
+                        Escape::None => {}
+                        Escape::Continue => {}
+                        Escape::Return(v) => return Ok(Escape::Return(v)),
+                        Escape::Break => return Ok(Escape::None),
+                    }
+                }
+
+                return Ok(Escape::None);
+            }
+
+            let iter_val = eval_expression(scopes, iter, prototypes)?;
+
+            match &iter_val {
+                Value::List(values) | Value::Tuple(values) => {
+                    for (i, value) in values.iter().enumerate() {
+                        let ret = eval_for_body(scopes, prototypes, lhs, i, value.clone(), block)?;
+
+                        match ret {
+                            Escape::None => {}
+                            Escape::Continue => {}
+                            Escape::Return(v) => return Ok(Escape::Return(v)),
+                            Escape::Break => return Ok(Escape::None),
+                        }
+                    }
+                }
+                _ => {
+                    return Err(format!(
+                        "iterator most be a list, found {}",
+                        Type::simple(&iter_val)
+                    ))
+                }
+            }
+        }
+        Statement::Break => return Ok(Escape::Break),
+        Statement::Continue => return Ok(Escape::Continue),
+        Statement::While(cond, block) => loop {
+            let value = eval_expression(scopes, cond, prototypes)?;
+
+            match value {
+                Value::Bool(b) => {
+                    if !b {
+                        break;
+                    }
+
+                    let ret = eval_statements(scopes, block, prototypes)?;
+
+                    match ret {
+                        Escape::None => {}
+                        Escape::Continue => {}
+                        Escape::Return(v) => return Ok(Escape::Return(v)),
+                        Escape::Break => return Ok(Escape::None),
+                    }
+                }
+                _ => return Err(format!("condition most be a boolean")),
+            }
+        },
+        Statement::Module(name, statements) => {
+            let module = eval_module(scopes, prototypes, name, statements)?;
+
+            scopes.declare(name, Value::Module(module), &None, DeclType::Immutable)?;
+        }
+        Statement::Type(name, datatype) => {
+            scopes.declare_type_alias(name, datatype)?;
+        }
+    };
+
+    Ok(Escape::None)
+}
+
+pub fn eval_statements(
+    scopes: &mut ScopeStack,
+    statements: &Vec<Statement>,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+) -> Result<Escape, String> {
+    let mut inner_scopes = scopes.new_from_push(HashMap::new());
+
+    for statement in statements {
+        let e = eval_statement(&mut inner_scopes, statement, prototypes)?;
+
+        if let Statement::Fn(..) = statement {
+            continue;
+        }
+
+        if let Escape::None = e {
+            continue;
+        }
+
+        return Ok(e);
+    }
+
+    Ok(Escape::None)
+}
+
+pub fn eval_statements_and_push_scope(
+    scopes: &mut ScopeStack,
+    statements: &Vec<Statement>,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+) -> Result<Escape, String> {
+    scopes.push(HashMap::new());
+
+    for statement in statements {
+        let e = eval_statement(scopes, statement, prototypes)?;
+
+        if let Statement::Fn(..) = statement {
+            continue;
+        }
+
+        if let Escape::None = e {
+            continue;
+        }
+
+        return Ok(e);
+    }
+
+    Ok(Escape::None)
+}
+
+pub fn eval_module(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    name: &String,
+    statements: &Vec<Statement>,
+) -> Result<BTreeMap<String, Value>, String> {
+    let mut exports: BTreeMap<String, Value> = BTreeMap::new();
+
+    let mut inner_scope = scopes.new_from_push(HashMap::new());
+    for statement in statements {
+        match statement {
+            Statement::Const(name, datatype, expr) => {
+                let value = eval_expression(&mut inner_scope, expr, prototypes)?;
+
+                // type checking
+                if let Some(datatype) = datatype {
+                    if &Type::from(&value) != datatype {
+                        return Err(format!(
+                            "expected {} found {}",
+                            datatype,
+                            Type::from(&value),
+                        ));
+                    }
+                }
+                exports.insert(name.to_string(), value);
+            }
+            Statement::Let(name, datatype, expr) => {
+                let value = eval_expression(&mut inner_scope, expr, prototypes)?;
+
+                // type checking
+                if let Some(datatype) = datatype {
+                    if &Type::from(&value) != datatype {
+                        return Err(format!(
+                            "expected {} found {}",
+                            datatype,
+                            Type::from(&value),
+                        ));
+                    }
+                }
+                exports.insert(name.to_string(), value);
+            }
+            Statement::Fn(name, args, ret_type, block) => {
+                exports.insert(
+                    name.to_string(),
+                    Value::Func(
+                        args.to_vec(),
+                        ret_type.clone(),
+                        block.to_vec(),
+                        Captured(inner_scope.clone()),
+                    ),
+                );
+            }
+            Statement::Module(name2, statements2) => {
+                let exports2 = eval_module(&mut inner_scope, prototypes, name2, statements2)?;
+                exports.insert(name2.to_string(), Value::Module(exports2));
+            }
+            other => return Err(format!("'{:?}' is not supported in modules", other)),
+        }
+    }
+
+    inner_scope.declare(
+        name,
+        Value::Module(exports.clone()),
+        &None,
+        DeclType::Immutable,
+    )?;
+    Ok(exports)
+}
+
+// runs one iteration with the loop variable and `index` in a new scope
+fn eval_for_body(
+    scopes: &mut ScopeStack,
+    prototypes: &HashMap<String, HashMap<String, Value>>,
+    lhs: &String,
+    index: usize,
+    value: Value,
+    block: &Vec<Statement>,
+) -> Result<Escape, String> {
+    let mut inner_scopes = scopes.new_from_push(HashMap::new());
+    let datatype = Type::from(&value);
+
+    inner_scopes.declare(lhs, value, &Some(datatype), DeclType::Mutable)?;
+    inner_scopes.declare(
+        &"index".to_string(),
+        Value::Int(index as i32),
+        &Some(Type::Builtin(BuiltinType::Int)),
+        DeclType::Immutable,
+    )?;
+
+    eval_statements(&mut inner_scopes, block, prototypes)
+}
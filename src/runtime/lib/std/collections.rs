@@ -1,53 +1,796 @@
-use std::collections::BTreeMap;
-
-use crate::runtime::value::Value;
-
-pub struct Collections(BTreeMap<String, Value>);
-
-impl Collections {
-    pub fn new() -> Self {
-        Self(BTreeMap::new())
-    }
-
-    pub fn exports() -> BTreeMap<String, Value> {
-        let mut std = Collections::new();
-
-        // collections functions
-        std.declare("set", Value::BuiltInFn(ak_collections::set));
-
-        return std.items();
-    }
-
-    pub fn declare(&mut self, name: &str, value: Value) {
-        self.0.insert(String::from(name), value);
-    }
-
-    fn items(self) -> BTreeMap<String, Value> {
-        return self.0;
-    }
-}
-
-mod ak_collections {
-    use crate::runtime::value::Value;
-
-    pub fn set(vs: Vec<Value>) -> Result<Value, String> {
-        match vs.get(0) {
-            Some(value) => {
-                if let Value::List(list) = value {
-                    let mut set = Vec::new();
-                    let list = list.to_vec();
-                    for val in list {
-                        if !set.contains(&val) {
-                            set.push(val);
-                        }
-                    }
-
-                    return Ok(Value::List(set));
-                } else {
-                    return Err(format!("the first argument most be a list"));
-                }
-            }
-            None => Err(format!("the first argument is required")),
-        }
-    }
-}
+use std::collections::BTreeMap;
+
+use crate::runtime::value::Value;
+
+pub struct Collections(BTreeMap<String, Value>);
+
+impl Collections {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn exports() -> BTreeMap<String, Value> {
+        let mut std = Collections::new();
+
+        // collections functions
+        std.declare("set", Value::BuiltInFn(ak_collections::set));
+        std.declare("push", Value::BuiltInFn(ak_collections::push));
+        std.declare("pop", Value::BuiltInFn(ak_collections::pop));
+        std.declare("len", Value::BuiltInFn(ak_collections::len));
+        std.declare("map", Value::HostFn(ak_collections::map));
+        std.declare("filter", Value::HostFn(ak_collections::filter));
+        std.declare("reduce", Value::HostFn(ak_collections::reduce));
+        std.declare("fold", Value::HostFn(ak_collections::reduce));
+        std.declare("sort", Value::BuiltInFn(ak_collections::sort));
+        std.declare("reverse", Value::BuiltInFn(ak_collections::reverse));
+        std.declare("contains", Value::BuiltInFn(ak_collections::contains));
+        std.declare("index_of", Value::BuiltInFn(ak_collections::index_of));
+        std.declare("slice", Value::BuiltInFn(ak_collections::slice));
+        std.declare("concat", Value::BuiltInFn(ak_collections::concat));
+        std.declare("flatten", Value::BuiltInFn(ak_collections::flatten));
+        std.declare("unique", Value::BuiltInFn(ak_collections::unique));
+        std.declare("zip", Value::BuiltInFn(ak_collections::zip));
+        std.declare("range", Value::BuiltInFn(ak_collections::range));
+        std.declare("enumerate", Value::BuiltInFn(ak_collections::enumerate));
+        std.declare("find", Value::HostFn(ak_collections::find));
+        std.declare("any", Value::HostFn(ak_collections::any));
+        std.declare("all", Value::HostFn(ak_collections::all));
+        std.declare("sum", Value::BuiltInFn(ak_collections::sum));
+        std.declare("product", Value::BuiltInFn(ak_collections::product));
+        std.declare("min", Value::BuiltInFn(ak_collections::min));
+        std.declare("max", Value::BuiltInFn(ak_collections::max));
+        std.declare("chunk", Value::BuiltInFn(ak_collections::chunk));
+        std.declare("insert", Value::BuiltInFn(ak_collections::insert));
+        std.declare("remove_at", Value::BuiltInFn(ak_collections::remove_at));
+
+        return std.items();
+    }
+
+    pub fn declare(&mut self, name: &str, value: Value) {
+        self.0.insert(String::from(name), value);
+    }
+
+    fn items(self) -> BTreeMap<String, Value> {
+        return self.0;
+    }
+}
+
+mod ak_collections {
+    use std::cmp::Ordering;
+    use std::collections::HashMap;
+
+    use crate::runtime::eval::expression::call_value;
+    use crate::runtime::value::{structural_eq, Value};
+    use crate::runtime::{ScopeStack, Simple, Type};
+
+    pub fn set(vs: Vec<Value>) -> Result<Value, String> {
+        match vs.get(0) {
+            Some(value) => {
+                if let Value::List(list) = value {
+                    let mut set = Vec::new();
+                    let list = list.to_vec();
+                    for val in list {
+                        if !set.contains(&val) {
+                            set.push(val);
+                        }
+                    }
+
+                    return Ok(Value::List(set));
+                } else {
+                    return Err(format!("the first argument most be a list"));
+                }
+            }
+            None => Err(format!("the first argument is required")),
+        }
+    }
+
+    // values are cloned when passed to functions, so this returns a new list
+    // and leaves the original untouched
+    pub fn push(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut list = list.to_vec();
+                list.push(vs[1].clone());
+
+                return Ok(Value::List(list));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    // returns (remaining, popped), see push
+    pub fn pop(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut list = list.to_vec();
+
+                match list.pop() {
+                    Some(value) => return Ok(Value::Tuple(vec![Value::List(list), value])),
+                    None => return Err(format!("cannot pop from an empty list")),
+                }
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn len(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => return Ok(Value::Int(list.len() as i32)),
+            Value::Object(props) => return Ok(Value::Int(props.len() as i32)),
+            Value::String(s) => return Ok(Value::Int(s.chars().count() as i32)),
+            _ => {
+                return Err(format!(
+                    "the first argument most be a list, object or string"
+                ))
+            }
+        }
+    }
+
+    pub fn map(
+        scopes: &mut ScopeStack,
+        vs: Vec<Value>,
+        prototypes: &HashMap<String, HashMap<String, Value>>,
+    ) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut res = Vec::new();
+
+                for item in list {
+                    let value = call_value(scopes, prototypes, vs[1].clone(), vec![item.clone()])?;
+                    res.push(value);
+                }
+
+                return Ok(Value::List(res));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn filter(
+        scopes: &mut ScopeStack,
+        vs: Vec<Value>,
+        prototypes: &HashMap<String, HashMap<String, Value>>,
+    ) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut res = Vec::new();
+
+                for (i, item) in list.iter().enumerate() {
+                    if test(scopes, prototypes, &vs[1], item, i)? {
+                        res.push(item.clone());
+                    }
+                }
+
+                return Ok(Value::List(res));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    // fn is called as fn(acc, item) and returns the next accumulator
+    pub fn reduce(
+        scopes: &mut ScopeStack,
+        vs: Vec<Value>,
+        prototypes: &HashMap<String, HashMap<String, Value>>,
+    ) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 3 {
+            return Err(format!("expected 3 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut acc = vs[1].clone();
+
+                for item in list {
+                    acc = call_value(scopes, prototypes, vs[2].clone(), vec![acc, item.clone()])?;
+                }
+
+                return Ok(acc);
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn sort(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 1 {
+            return Err(format!("expected 1 or 2 argument, but found {}", vs.len()));
+        }
+
+        let descending = match vs.get(1) {
+            Some(Value::Bool(b)) => *b,
+            Some(_) => return Err(format!("the second argument most be a bool")),
+            None => false,
+        };
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut list = list.to_vec();
+
+                comparable(&list, "sort")?;
+
+                // items are known to be of one comparable type at this point
+                list.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+                if descending {
+                    list.reverse();
+                }
+
+                return Ok(Value::List(list));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn reverse(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut list = list.to_vec();
+                list.reverse();
+
+                return Ok(Value::List(list));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn contains(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                return Ok(Value::Bool(
+                    list.iter().any(|item| structural_eq(item, &vs[1])),
+                ))
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn index_of(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 2 {
+            return Err(format!("expected 2 or 3 argument, but found {}", vs.len()));
+        }
+
+        let start = match vs.get(2) {
+            Some(Value::Int(n)) if *n >= 0 => *n as usize,
+            Some(Value::Int(n)) => {
+                return Err(format!("the start offset {} most not be negative", n))
+            }
+            Some(_) => return Err(format!("the third argument most be an integer")),
+            None => 0,
+        };
+
+        match &vs[0] {
+            Value::List(list) => {
+                for (i, item) in list.iter().enumerate().skip(start) {
+                    if structural_eq(item, &vs[1]) {
+                        return Ok(Value::Int(i as i32));
+                    }
+                }
+
+                return Ok(Value::Int(-1));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn slice(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 2 {
+            return Err(format!("expected 2 or 3 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let start = match &vs[1] {
+                    Value::Int(n) => clamp_index(*n, list.len()),
+                    _ => return Err(format!("the second argument most be an integer")),
+                };
+                let end = match vs.get(2) {
+                    Some(Value::Int(n)) => clamp_index(*n, list.len()),
+                    Some(_) => return Err(format!("the third argument most be an integer")),
+                    None => list.len(),
+                };
+
+                if start >= end {
+                    return Ok(Value::List(vec![]));
+                }
+
+                return Ok(Value::List(list[start..end].to_vec()));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn concat(vs: Vec<Value>) -> Result<Value, String> {
+        let mut res = Vec::new();
+
+        for (i, value) in vs.iter().enumerate() {
+            match value {
+                Value::List(list) => res.extend(list.iter().cloned()),
+                other => {
+                    return Err(format!(
+                        "expected a list at argument {}, found {}",
+                        i + 1,
+                        Type::simple(other)
+                    ))
+                }
+            }
+        }
+
+        return Ok(Value::List(res));
+    }
+
+    // depth defaults to 1, and -1 flattens all the way down
+    pub fn flatten(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 1 {
+            return Err(format!("expected 1 or 2 argument, but found {}", vs.len()));
+        }
+
+        let depth = match vs.get(1) {
+            Some(Value::Int(n)) if *n >= -1 => *n,
+            Some(Value::Int(n)) => {
+                return Err(format!("invalid depth {}, use -1 to flatten fully", n))
+            }
+            Some(_) => return Err(format!("the second argument most be an integer")),
+            None => 1,
+        };
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut res = Vec::new();
+                flatten_into(list, depth, &mut res);
+
+                return Ok(Value::List(res));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn unique(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut res: Vec<Value> = Vec::new();
+
+                for item in list {
+                    if !res.iter().any(|seen| structural_eq(seen, item)) {
+                        res.push(item.clone());
+                    }
+                }
+
+                return Ok(Value::List(res));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    // any number of lists can be zipped, the result is as long as the shortest
+    pub fn zip(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() < 2 {
+            return Err(format!(
+                "expected at least 2 argument, but found {}",
+                vs.len()
+            ));
+        }
+
+        let mut lists = Vec::new();
+
+        for (i, value) in vs.iter().enumerate() {
+            match value {
+                Value::List(list) => lists.push(list),
+                other => {
+                    return Err(format!(
+                        "expected a list at argument {}, found {}",
+                        i + 1,
+                        Type::simple(other)
+                    ))
+                }
+            }
+        }
+
+        let len = lists.iter().map(|list| list.len()).min().unwrap_or(0);
+        let mut res = Vec::new();
+
+        for i in 0..len {
+            res.push(Value::Tuple(
+                lists.iter().map(|list| list[i].clone()).collect(),
+            ));
+        }
+
+        return Ok(Value::List(res));
+    }
+
+    // range(end), range(start, end) or range(start, end, step), the end is excluded
+    pub fn range(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 1 {
+            return Err(format!("expected 1 to 3 argument, but found {}", vs.len()));
+        }
+
+        let mut nums = Vec::new();
+
+        for (i, value) in vs.iter().enumerate() {
+            match value {
+                Value::Int(n) => nums.push(*n as i64),
+                other => {
+                    return Err(format!(
+                        "expected an integer at argument {}, found {}",
+                        i + 1,
+                        Type::simple(other)
+                    ))
+                }
+            }
+        }
+
+        let (start, end, step) = match nums[..] {
+            [end] => (0, end, 1),
+            [start, end] => (start, end, 1),
+            [start, end, step] => (start, end, step),
+            _ => unreachable!(),
+        };
+
+        if step == 0 {
+            return Err(format!("the step most not be zero"));
+        }
+
+        let mut res = Vec::new();
+        let mut n = start;
+
+        while (step > 0 && n < end) || (step < 0 && n > end) {
+            res.push(Value::Int(n as i32));
+            n += step;
+        }
+
+        return Ok(Value::List(res));
+    }
+
+    pub fn enumerate(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                let mut res = Vec::new();
+
+                for (i, item) in list.iter().enumerate() {
+                    res.push(Value::Tuple(vec![Value::Int(i as i32), item.clone()]));
+                }
+
+                return Ok(Value::List(res));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    // returns null when no item matches
+    pub fn find(
+        scopes: &mut ScopeStack,
+        vs: Vec<Value>,
+        prototypes: &HashMap<String, HashMap<String, Value>>,
+    ) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                for (i, item) in list.iter().enumerate() {
+                    if test(scopes, prototypes, &vs[1], item, i)? {
+                        return Ok(item.clone());
+                    }
+                }
+
+                return Ok(Value::Null);
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn any(
+        scopes: &mut ScopeStack,
+        vs: Vec<Value>,
+        prototypes: &HashMap<String, HashMap<String, Value>>,
+    ) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                for (i, item) in list.iter().enumerate() {
+                    if test(scopes, prototypes, &vs[1], item, i)? {
+                        return Ok(Value::Bool(true));
+                    }
+                }
+
+                return Ok(Value::Bool(false));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn all(
+        scopes: &mut ScopeStack,
+        vs: Vec<Value>,
+        prototypes: &HashMap<String, HashMap<String, Value>>,
+    ) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                for (i, item) in list.iter().enumerate() {
+                    if !test(scopes, prototypes, &vs[1], item, i)? {
+                        return Ok(Value::Bool(false));
+                    }
+                }
+
+                return Ok(Value::Bool(true));
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn sum(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => aggregate(list, 0, i32::checked_add, |a, b| a + b),
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn product(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => aggregate(list, 1, i32::checked_mul, |a, b| a * b),
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn min(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                comparable(list, "take the min of")?;
+
+                match list
+                    .iter()
+                    .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                {
+                    Some(value) => return Ok(value.clone()),
+                    None => return Err(format!("cannot take the min of an empty list")),
+                }
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn max(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::List(list) => {
+                comparable(list, "take the max of")?;
+
+                match list
+                    .iter()
+                    .max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+                {
+                    Some(value) => return Ok(value.clone()),
+                    None => return Err(format!("cannot take the max of an empty list")),
+                }
+            }
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn chunk(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::List(list), Value::Int(size)) => {
+                if *size <= 0 {
+                    return Err(format!("the chunk size most be positive, found {}", size));
+                }
+
+                let chunks = list
+                    .chunks(*size as usize)
+                    .map(|chunk| Value::List(chunk.to_vec()))
+                    .collect();
+
+                return Ok(Value::List(chunks));
+            }
+            (Value::List(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    // inserting at the length of the list appends to it
+    pub fn insert(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 3 {
+            return Err(format!("expected 3 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::List(list), Value::Int(index)) => {
+                if *index < 0 || *index as usize > list.len() {
+                    return Err(format!(
+                        "index {} is out of range, expected 0 to {}",
+                        index,
+                        list.len()
+                    ));
+                }
+
+                let mut list = list.to_vec();
+                list.insert(*index as usize, vs[2].clone());
+
+                return Ok(Value::List(list));
+            }
+            (Value::List(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn remove_at(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::List(list), Value::Int(index)) => {
+                if *index < 0 || *index as usize >= list.len() {
+                    return Err(format!(
+                        "index {} is out of range, expected 0 to {}",
+                        index,
+                        list.len() as i32 - 1
+                    ));
+                }
+
+                let mut list = list.to_vec();
+                list.remove(*index as usize);
+
+                return Ok(Value::List(list));
+            }
+            (Value::List(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    // negative indexes count from the end, out of range indexes are clamped
+    fn clamp_index(index: i32, len: usize) -> usize {
+        if index < 0 {
+            return len.saturating_sub(index.unsigned_abs() as usize);
+        }
+
+        (index as usize).min(len)
+    }
+
+    fn flatten_into(list: &Vec<Value>, depth: i32, res: &mut Vec<Value>) {
+        for item in list {
+            match item {
+                Value::List(inner) if depth != 0 => flatten_into(inner, depth - 1, res),
+                _ => res.push(item.clone()),
+            }
+        }
+    }
+
+    // calls a predicate, which most return a bool
+    fn test(
+        scopes: &mut ScopeStack,
+        prototypes: &HashMap<String, HashMap<String, Value>>,
+        predicate: &Value,
+        item: &Value,
+        index: usize,
+    ) -> Result<bool, String> {
+        match call_value(scopes, prototypes, predicate.clone(), vec![item.clone()])? {
+            Value::Bool(b) => Ok(b),
+            other => Err(format!(
+                "the predicate most return a bool, but returned {} for the item at index {}",
+                Type::simple(&other),
+                index
+            )),
+        }
+    }
+
+    // ints stay ints until a float shows up, then the result is a float
+    fn aggregate(
+        list: &Vec<Value>,
+        init: i32,
+        int_op: fn(i32, i32) -> Option<i32>,
+        float_op: fn(f32, f32) -> f32,
+    ) -> Result<Value, String> {
+        let mut acc = Value::Int(init);
+
+        for (i, item) in list.iter().enumerate() {
+            acc = match (&acc, item) {
+                (Value::Int(a), Value::Int(b)) => match int_op(*a, *b) {
+                    Some(n) => Value::Int(n),
+                    None => return Err(format!("integer overflow at index {}", i)),
+                },
+                (Value::Int(a), Value::Float(b)) => Value::Float(float_op(*a as f32, *b)),
+                (Value::Float(a), Value::Int(b)) => Value::Float(float_op(*a, *b as f32)),
+                (Value::Float(a), Value::Float(b)) => Value::Float(float_op(*a, *b)),
+                (_, other) => {
+                    return Err(format!(
+                        "expected a number at index {}, found {}",
+                        i,
+                        Type::simple(other)
+                    ))
+                }
+            };
+        }
+
+        Ok(acc)
+    }
+
+    // only lists of ints, floats or strings can be ordered, and not mixed
+    fn comparable(list: &Vec<Value>, action: &str) -> Result<(), String> {
+        if let Some(first) = list.get(0) {
+            match first {
+                Value::Int(_) | Value::Float(_) | Value::String(_) => {}
+                other => {
+                    return Err(format!(
+                        "cannot {} a list of {}, expected int, float or string",
+                        action,
+                        Type::simple(other)
+                    ))
+                }
+            }
+
+            for item in list {
+                if std::mem::discriminant(item) != std::mem::discriminant(first) {
+                    return Err(format!(
+                        "cannot compare {} with {}",
+                        Type::simple(first),
+                        Type::simple(item)
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
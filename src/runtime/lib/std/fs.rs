@@ -1,195 +1,635 @@
-use std::collections::BTreeMap;
-
-use crate::runtime::value::Value;
-
-pub struct Fs(BTreeMap<String, Value>);
-
-impl Fs {
-    pub fn new() -> Self {
-        Self(BTreeMap::new())
-    }
-
-    pub fn exports() -> BTreeMap<String, Value> {
-        let mut fs = Fs::new();
-
-        // fs functions
-        fs.declare("read_file", Value::BuiltInFn(ak_fs::read_file));
-        fs.declare("read_dir", Value::BuiltInFn(ak_fs::read_dir));
-        fs.declare("remove_file", Value::BuiltInFn(ak_fs::remove_file));
-        fs.declare("remove_dir", Value::BuiltInFn(ak_fs::remove_dir));
-        fs.declare("rename_file", Value::BuiltInFn(ak_fs::rename_file));
-        fs.declare("write_file", Value::BuiltInFn(ak_fs::write_file));
-
-        return fs.items();
-    }
-
-    pub fn declare(&mut self, name: &str, value: Value) {
-        self.0.insert(String::from(name), value);
-    }
-
-    fn items(self) -> BTreeMap<String, Value> {
-        return self.0;
-    }
-}
-
-mod ak_fs {
-    use crate::runtime::value::Value;
-    use std::fs;
-
-    pub fn read_file(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 1 || vs.len() < 1 {
-            return Err(format!("expected 1 argument, but found {}", vs.len()));
-        }
-
-        match vs.get(0) {
-            Some(value) => match value {
-                Value::String(s) => {
-                    let file_result = fs::read_to_string(s);
-
-                    match file_result {
-                        Ok(content) => return Ok(Value::String(content)),
-                        Err(e) => return Err(e.to_string()),
-                    }
-                }
-                _ => return Err(format!("the first argument most be a string")),
-            },
-            None => {
-                return Err(format!("expected 1 argument, but found {}", vs.len()));
-            }
-        }
-    }
-
-    pub fn write_file(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 2 || vs.len() < 2 {
-            return Err(format!("expected 2 argument, but found {}", vs.len()));
-        }
-
-        match vs.get(0) {
-            Some(v1) => match v1 {
-                Value::String(path) => match vs.get(1) {
-                    Some(v2) => match v2 {
-                        Value::String(content) => {
-                            let res = fs::write(path, content);
-                            match res {
-                                Ok(_) => return Ok(Value::Null),
-                                Err(e) => return Err(e.to_string()),
-                            }
-                        }
-                        _ => return Err(format!("the seconde argument most be a string")),
-                    },
-                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
-                },
-                _ => return Err(format!("the first argument most be a string")),
-            },
-            None => {
-                return Err(format!("expected 2 argument, but found {}", vs.len()));
-            }
-        }
-    }
-
-    pub fn rename_file(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 2 || vs.len() < 2 {
-            return Err(format!("expected 2 argument, but found {}", vs.len()));
-        }
-
-        match vs.get(0) {
-            Some(v1) => match v1 {
-                Value::String(from) => match vs.get(1) {
-                    Some(v2) => match v2 {
-                        Value::String(to) => {
-                            let res = fs::rename(from, to);
-                            match res {
-                                Ok(_) => return Ok(Value::Null),
-                                Err(e) => return Err(e.to_string()),
-                            }
-                        }
-                        _ => return Err(format!("the first argument most be a string")),
-                    },
-                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
-                },
-                _ => return Err(format!("the first argument most be a string")),
-            },
-            None => {
-                return Err(format!("expected 2 argument, but found {}", vs.len()));
-            }
-        }
-    }
-
-    pub fn remove_file(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 1 || vs.len() < 1 {
-            return Err(format!("expected 1 argument, but found {}", vs.len()));
-        }
-
-        match vs.get(0) {
-            Some(value) => match value {
-                Value::String(s) => {
-                    let file_result = fs::remove_file(s);
-
-                    match file_result {
-                        Ok(_) => return Ok(Value::Null),
-                        Err(e) => return Err(e.to_string()),
-                    }
-                }
-                _ => return Err(format!("the first argument most be a string")),
-            },
-            None => {
-                return Err(format!("expected 1 argument, but found {}", vs.len()));
-            }
-        }
-    }
-
-    pub fn read_dir(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 1 || vs.len() < 1 {
-            return Err(format!("expected 1 argument, but found {}", vs.len()));
-        }
-
-        match vs.get(0) {
-            Some(value) => match value {
-                Value::String(s) => {
-                    let file_result = fs::read_dir(s);
-
-                    match file_result {
-                        Ok(rd) => {
-                            let mut items = vec![];
-
-                            for entry in rd.into_iter() {
-                                let t = entry.unwrap().file_name().to_string_lossy().to_string();
-                                items.push(Value::String(t));
-                            }
-
-                            return Ok(Value::List(items));
-                        }
-                        Err(e) => return Err(e.to_string()),
-                    }
-                }
-                _ => return Err(format!("the first argument most be a string")),
-            },
-            None => {
-                return Err(format!("expected 1 argument, but found {}", vs.len()));
-            }
-        }
-    }
-
-    pub fn remove_dir(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 1 || vs.len() < 1 {
-            return Err(format!("expected 1 argument, but found {}", vs.len()));
-        }
-
-        match vs.get(0) {
-            Some(value) => match value {
-                Value::String(s) => {
-                    let file_result = fs::remove_dir(s);
-
-                    match file_result {
-                        Ok(_) => return Ok(Value::Null),
-                        Err(e) => return Err(e.to_string()),
-                    }
-                }
-                _ => return Err(format!("the first argument most be a string")),
-            },
-            None => {
-                return Err(format!("expected 1 argument, but found {}", vs.len()));
-            }
-        }
-    }
-}
+use std::collections::BTreeMap;
+
+use crate::runtime::value::Value;
+
+pub struct Fs(BTreeMap<String, Value>);
+
+impl Fs {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn exports() -> BTreeMap<String, Value> {
+        let mut fs = Fs::new();
+
+        // fs functions
+        fs.declare("read_file", Value::BuiltInFn(ak_fs::read_file));
+        fs.declare("read_lines", Value::BuiltInFn(ak_fs::read_lines));
+        fs.declare("read_dir", Value::BuiltInFn(ak_fs::read_dir));
+        fs.declare("remove_file", Value::BuiltInFn(ak_fs::remove_file));
+        fs.declare("remove_dir", Value::BuiltInFn(ak_fs::remove_dir));
+        fs.declare("rename_file", Value::BuiltInFn(ak_fs::rename_file));
+        fs.declare("write_file", Value::BuiltInFn(ak_fs::write_file));
+        fs.declare("append", Value::BuiltInFn(ak_fs::append_file));
+        fs.declare("exists", Value::BuiltInFn(ak_fs::exists));
+        fs.declare("copy", Value::BuiltInFn(ak_fs::copy));
+        fs.declare("create_dir", Value::BuiltInFn(ak_fs::create_dir));
+        fs.declare("create_dir_all", Value::BuiltInFn(ak_fs::create_dir_all));
+        fs.declare("metadata", Value::BuiltInFn(ak_fs::metadata));
+        fs.declare("is_file", Value::BuiltInFn(ak_fs::is_file));
+        fs.declare("is_dir", Value::BuiltInFn(ak_fs::is_dir));
+        fs.declare("canonicalize", Value::BuiltInFn(ak_fs::canonicalize));
+        fs.declare("absolute", Value::BuiltInFn(ak_fs::canonicalize));
+        fs.declare("read_bytes", Value::BuiltInFn(ak_fs::read_bytes));
+        fs.declare("write_bytes", Value::BuiltInFn(ak_fs::write_bytes));
+        fs.declare("open", Value::BuiltInFn(ak_fs::open));
+        fs.declare("read_line", Value::BuiltInFn(ak_fs::read_line));
+        fs.declare("write", Value::BuiltInFn(ak_fs::write));
+        fs.declare("close", Value::BuiltInFn(ak_fs::close));
+
+        return fs.items();
+    }
+
+    pub fn declare(&mut self, name: &str, value: Value) {
+        self.0.insert(String::from(name), value);
+    }
+
+    fn items(self) -> BTreeMap<String, Value> {
+        return self.0;
+    }
+}
+
+mod ak_fs {
+    use crate::runtime::value::{FileHandle, KeyValue, Value};
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::{Arc, Mutex};
+
+    pub fn read_file(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    let file_result = fs::read_to_string(s);
+
+                    match file_result {
+                        Ok(content) => return Ok(Value::String(content)),
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn write_file(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(v1) => match v1 {
+                Value::String(path) => match vs.get(1) {
+                    Some(v2) => match v2 {
+                        Value::String(content) => {
+                            let res = fs::write(path, content);
+                            match res {
+                                Ok(_) => return Ok(Value::Null),
+                                Err(e) => return Err(e.to_string()),
+                            }
+                        }
+                        _ => return Err(format!("the seconde argument most be a string")),
+                    },
+                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 2 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn rename_file(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(v1) => match v1 {
+                Value::String(from) => match vs.get(1) {
+                    Some(v2) => match v2 {
+                        Value::String(to) => {
+                            let res = fs::rename(from, to);
+                            match res {
+                                Ok(_) => return Ok(Value::Null),
+                                Err(e) => return Err(e.to_string()),
+                            }
+                        }
+                        _ => return Err(format!("the first argument most be a string")),
+                    },
+                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 2 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn remove_file(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    let file_result = fs::remove_file(s);
+
+                    match file_result {
+                        Ok(_) => return Ok(Value::Null),
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn read_dir(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    let file_result = fs::read_dir(s);
+
+                    match file_result {
+                        Ok(rd) => {
+                            let mut items = vec![];
+
+                            for entry in rd.into_iter() {
+                                let t = entry.unwrap().file_name().to_string_lossy().to_string();
+                                items.push(Value::String(t));
+                            }
+
+                            return Ok(Value::List(items));
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn remove_dir(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    let file_result = fs::remove_dir(s);
+
+                    match file_result {
+                        Ok(_) => return Ok(Value::Null),
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn append_file(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(v1) => match v1 {
+                Value::String(path) => match vs.get(1) {
+                    Some(v2) => match v2 {
+                        Value::String(content) => {
+                            let res = fs::OpenOptions::new()
+                                .create(true)
+                                .append(true)
+                                .open(path)
+                                .and_then(|mut file| file.write_all(content.as_bytes()));
+
+                            match res {
+                                Ok(_) => return Ok(Value::Null),
+                                Err(e) => return Err(format!("{}: {}", path, e)),
+                            }
+                        }
+                        _ => return Err(format!("the second argument most be a string")),
+                    },
+                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 2 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn exists(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    // anything that can't be confirmed, like a permission error, is false
+                    return Ok(Value::Bool(fs::metadata(s).is_ok()));
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn copy(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(v1) => match v1 {
+                Value::String(from) => match vs.get(1) {
+                    Some(v2) => match v2 {
+                        Value::String(to) => match fs::copy(from, to) {
+                            Ok(bytes) => match i32::try_from(bytes) {
+                                Ok(bytes) => return Ok(Value::Int(bytes)),
+                                Err(_) => {
+                                    return Err(format!(
+                                        "copied {} bytes, which does not fit in an int",
+                                        bytes
+                                    ))
+                                }
+                            },
+                            Err(e) => return Err(format!("cannot copy {} to {}: {}", from, to, e)),
+                        },
+                        _ => return Err(format!("the second argument most be a string")),
+                    },
+                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 2 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn create_dir(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => match fs::create_dir(s) {
+                    Ok(_) => return Ok(Value::Null),
+                    Err(e) => return Err(format!("{}: {}", s, e)),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn create_dir_all(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => match fs::create_dir_all(s) {
+                    Ok(_) => return Ok(Value::Null),
+                    Err(e) => return Err(format!("{}: {}", s, e)),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn metadata(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => match fs::metadata(s) {
+                    Ok(meta) => {
+                        let size = match i32::try_from(meta.len()) {
+                            Ok(size) => size,
+                            Err(_) => {
+                                return Err(format!(
+                                    "the size of {} is {} bytes, which does not fit in an int",
+                                    s,
+                                    meta.len()
+                                ))
+                            }
+                        };
+
+                        return Ok(Value::Object(vec![
+                            KeyValue {
+                                key: "size".to_string(),
+                                value: Value::Int(size),
+                            },
+                            KeyValue {
+                                key: "is_file".to_string(),
+                                value: Value::Bool(meta.is_file()),
+                            },
+                            KeyValue {
+                                key: "is_dir".to_string(),
+                                value: Value::Bool(meta.is_dir()),
+                            },
+                            KeyValue {
+                                key: "readonly".to_string(),
+                                value: Value::Bool(meta.permissions().readonly()),
+                            },
+                        ]));
+                    }
+                    Err(e) => return Err(format!("{}: {}", s, e)),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn read_lines(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => match fs::read_to_string(s) {
+                    Ok(content) => {
+                        let lines = content
+                            .lines()
+                            .map(|line| Value::String(line.to_string()))
+                            .collect();
+
+                        return Ok(Value::List(lines));
+                    }
+                    Err(e) => return Err(format!("{}: {}", s, e)),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn is_file(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    // missing paths are not an error, they are just false
+                    let res = fs::metadata(s).map(|meta| meta.is_file()).unwrap_or(false);
+                    return Ok(Value::Bool(res));
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn is_dir(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    let res = fs::metadata(s).map(|meta| meta.is_dir()).unwrap_or(false);
+                    return Ok(Value::Bool(res));
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn canonicalize(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => match fs::canonicalize(s) {
+                    Ok(path) => return Ok(Value::String(path.to_string_lossy().to_string())),
+                    Err(e) => return Err(format!("{}: {}", s, e)),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn read_bytes(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => match fs::read(s) {
+                    Ok(bytes) => {
+                        let bytes = bytes.into_iter().map(|b| Value::Int(b as i32)).collect();
+                        return Ok(Value::List(bytes));
+                    }
+                    Err(e) => return Err(format!("{}: {}", s, e)),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 1 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    pub fn write_bytes(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(v1) => match v1 {
+                Value::String(path) => match vs.get(1) {
+                    Some(v2) => match v2 {
+                        Value::List(list) => {
+                            let mut bytes = Vec::new();
+
+                            // check every byte before touching the file
+                            for (i, item) in list.iter().enumerate() {
+                                match item {
+                                    Value::Int(n) if *n >= 0 && *n <= 255 => bytes.push(*n as u8),
+                                    other => {
+                                        return Err(format!(
+                                            "expected a byte (0 to 255) at index {}, found {}",
+                                            i, other
+                                        ))
+                                    }
+                                }
+                            }
+
+                            match fs::write(path, bytes) {
+                                Ok(_) => return Ok(Value::Null),
+                                Err(e) => return Err(format!("{}: {}", path, e)),
+                            }
+                        }
+                        _ => return Err(format!("the second argument most be a list")),
+                    },
+                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => {
+                return Err(format!("expected 2 argument, but found {}", vs.len()));
+            }
+        }
+    }
+
+    // mode is "r" to read, "w" to write or "a" to append
+    pub fn open(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(path), Value::String(mode)) => {
+                let res = match mode.as_str() {
+                    "r" => fs::File::open(path),
+                    "w" => fs::File::create(path),
+                    "a" => fs::OpenOptions::new().create(true).append(true).open(path),
+                    other => {
+                        return Err(format!(
+                            "unknown mode {:?}, expected \"r\", \"w\" or \"a\"",
+                            other
+                        ))
+                    }
+                };
+
+                match res {
+                    Ok(file) => {
+                        let handle = Arc::new(Mutex::new(Some(BufReader::new(file))));
+                        return Ok(Value::FileHandle(FileHandle(handle)));
+                    }
+                    Err(e) => return Err(format!("{}: {}", path, e)),
+                }
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    // returns the next line without its line ending, or null at the end of the file
+    pub fn read_line(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::FileHandle(handle) => match handle.0.lock().unwrap().as_mut() {
+                Some(reader) => {
+                    let mut line = String::new();
+
+                    match reader.read_line(&mut line) {
+                        Ok(0) => return Ok(Value::Null),
+                        Ok(_) => {
+                            if line.ends_with('\n') {
+                                line.pop();
+                                if line.ends_with('\r') {
+                                    line.pop();
+                                }
+                            }
+
+                            return Ok(Value::String(line));
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                None => return Err(format!("the file is closed")),
+            },
+            _ => return Err(format!("the first argument most be a file")),
+        }
+    }
+
+    pub fn write(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::FileHandle(handle), Value::String(content)) => {
+                match handle.0.lock().unwrap().as_mut() {
+                    Some(reader) => match reader.get_mut().write_all(content.as_bytes()) {
+                        Ok(_) => return Ok(Value::Null),
+                        Err(e) => return Err(e.to_string()),
+                    },
+                    None => return Err(format!("the file is closed")),
+                }
+            }
+            (Value::FileHandle(_), _) => {
+                return Err(format!("the second argument most be a string"))
+            }
+            _ => return Err(format!("the first argument most be a file")),
+        }
+    }
+
+    pub fn close(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::FileHandle(handle) => match handle.0.lock().unwrap().take() {
+                Some(_) => return Ok(Value::Null),
+                None => return Err(format!("the file is already closed")),
+            },
+            _ => return Err(format!("the first argument most be a file")),
+        }
+    }
+}
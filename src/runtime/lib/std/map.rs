@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use crate::runtime::value::Value;
+
+pub struct Map(BTreeMap<String, Value>);
+
+impl Map {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn exports() -> BTreeMap<String, Value> {
+        let mut map = Map::new();
+
+        // map functions
+        map.declare("new", Value::BuiltInFn(ak_map::new));
+        map.declare("get", Value::BuiltInFn(ak_map::get));
+        map.declare("set", Value::BuiltInFn(ak_map::set));
+        map.declare("has", Value::BuiltInFn(ak_map::has));
+        map.declare("remove", Value::BuiltInFn(ak_map::remove));
+
+        return map.items();
+    }
+
+    pub fn declare(&mut self, name: &str, value: Value) {
+        self.0.insert(String::from(name), value);
+    }
+
+    fn items(self) -> BTreeMap<String, Value> {
+        return self.0;
+    }
+}
+
+// maps are values like lists, so set and remove return a new map
+mod ak_map {
+    use std::collections::BTreeMap;
+
+    use crate::runtime::value::Value;
+
+    pub fn new(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        Ok(Value::Map(BTreeMap::new()))
+    }
+
+    // returns null for a missing key
+    pub fn get(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::Map(items), Value::String(key)) => match items.get(key) {
+                Some(value) => return Ok(value.clone()),
+                None => return Ok(Value::Null),
+            },
+            (Value::Map(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a map")),
+        }
+    }
+
+    pub fn set(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 3 {
+            return Err(format!("expected 3 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::Map(items), Value::String(key)) => {
+                let mut items = items.to_owned();
+                items.insert(key.to_string(), vs[2].clone());
+
+                return Ok(Value::Map(items));
+            }
+            (Value::Map(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a map")),
+        }
+    }
+
+    pub fn has(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::Map(items), Value::String(key)) => {
+                return Ok(Value::Bool(items.contains_key(key)))
+            }
+            (Value::Map(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a map")),
+        }
+    }
+
+    pub fn remove(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::Map(items), Value::String(key)) => {
+                let mut items = items.to_owned();
+                items.remove(key);
+
+                return Ok(Value::Map(items));
+            }
+            (Value::Map(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a map")),
+        }
+    }
+}
@@ -0,0 +1,667 @@
+use std::collections::BTreeMap;
+
+use crate::runtime::value::Value;
+
+pub struct Math(BTreeMap<String, Value>);
+
+impl Math {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn exports() -> BTreeMap<String, Value> {
+        let mut math = Math::new();
+
+        // math functions
+        math.declare("sqrt", Value::BuiltInFn(ak_math::sqrt));
+        math.declare("ln", Value::BuiltInFn(ak_math::ln));
+        math.declare("log10", Value::BuiltInFn(ak_math::log10));
+        math.declare("log", Value::BuiltInFn(ak_math::log));
+        math.declare("floor", Value::BuiltInFn(ak_math::floor));
+        math.declare("ceil", Value::BuiltInFn(ak_math::ceil));
+        math.declare("round", Value::BuiltInFn(ak_math::round));
+        math.declare("min", Value::BuiltInFn(ak_math::min));
+        math.declare("max", Value::BuiltInFn(ak_math::max));
+        math.declare("clamp", Value::BuiltInFn(ak_math::clamp));
+        math.declare("rem", Value::BuiltInFn(ak_math::rem));
+        math.declare("gcd", Value::BuiltInFn(ak_math::gcd));
+        math.declare("lcm", Value::BuiltInFn(ak_math::lcm));
+        math.declare("hypot", Value::BuiltInFn(ak_math::hypot));
+        math.declare("atan2", Value::BuiltInFn(ak_math::atan2));
+        math.declare("to_radians", Value::BuiltInFn(ak_math::to_radians));
+        math.declare("to_degrees", Value::BuiltInFn(ak_math::to_degrees));
+        math.declare("sign", Value::BuiltInFn(ak_math::sign));
+        math.declare("asin", Value::BuiltInFn(ak_math::asin));
+        math.declare("acos", Value::BuiltInFn(ak_math::acos));
+        math.declare("atan", Value::BuiltInFn(ak_math::atan));
+        math.declare("sinh", Value::BuiltInFn(ak_math::sinh));
+        math.declare("cosh", Value::BuiltInFn(ak_math::cosh));
+        math.declare("tanh", Value::BuiltInFn(ak_math::tanh));
+        math.declare("trunc", Value::BuiltInFn(ak_math::trunc));
+        math.declare("fract", Value::BuiltInFn(ak_math::fract));
+        math.declare("factorial", Value::BuiltInFn(ak_math::factorial));
+        math.declare("comb", Value::BuiltInFn(ak_math::comb));
+
+        return math.items();
+    }
+
+    pub fn declare(&mut self, name: &str, value: Value) {
+        self.0.insert(String::from(name), value);
+    }
+
+    fn items(self) -> BTreeMap<String, Value> {
+        return self.0;
+    }
+}
+
+mod ak_math {
+    use std::f32::consts::PI;
+
+    use crate::runtime::value::Value;
+
+    pub fn sqrt(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => {
+                    if n < 0.0 {
+                        return Err(format!("cannot take the square root of a negative number"));
+                    }
+
+                    return Ok(Value::Float(n.sqrt()));
+                }
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn ln(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => {
+                    if n <= 0.0 {
+                        return Err(format!(
+                            "cannot take the logarithm of a non-positive number"
+                        ));
+                    }
+
+                    return Ok(Value::Float(n.ln()));
+                }
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn log10(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => {
+                    if n <= 0.0 {
+                        return Err(format!(
+                            "cannot take the logarithm of a non-positive number"
+                        ));
+                    }
+
+                    return Ok(Value::Float(n.log10()));
+                }
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn log(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(v1) => match number(v1) {
+                Some(base) => match vs.get(1) {
+                    Some(v2) => match number(v2) {
+                        Some(n) => {
+                            if base <= 0.0 || base == 1.0 {
+                                return Err(format!(
+                                    "the base of a logarithm most be positive and not equal to 1"
+                                ));
+                            }
+
+                            if n <= 0.0 {
+                                return Err(format!(
+                                    "cannot take the logarithm of a non-positive number"
+                                ));
+                            }
+
+                            return Ok(Value::Float(n.log(base)));
+                        }
+                        None => return Err(format!("the second argument most be a number")),
+                    },
+                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+                },
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn floor(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::Int(n) => return Ok(Value::Int(*n)),
+                Value::Float(n) => return integer(n.floor()),
+                _ => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn ceil(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::Int(n) => return Ok(Value::Int(*n)),
+                Value::Float(n) => return integer(n.ceil()),
+                _ => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn round(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::Int(n) => return Ok(Value::Int(*n)),
+                Value::Float(n) => return integer(n.round()),
+                _ => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn min(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() < 1 {
+            return Err(format!(
+                "expected at least 1 argument, but found {}",
+                vs.len()
+            ));
+        }
+
+        let mut res = &vs[0];
+
+        for (i, value) in vs.iter().enumerate() {
+            match (number(value), number(res)) {
+                (Some(n), Some(m)) => {
+                    if n < m {
+                        res = value;
+                    }
+                }
+                _ => return Err(format!("the argument {} most be a number", i + 1)),
+            }
+        }
+
+        Ok(res.clone())
+    }
+
+    pub fn max(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() < 1 {
+            return Err(format!(
+                "expected at least 1 argument, but found {}",
+                vs.len()
+            ));
+        }
+
+        let mut res = &vs[0];
+
+        for (i, value) in vs.iter().enumerate() {
+            match (number(value), number(res)) {
+                (Some(n), Some(m)) => {
+                    if n > m {
+                        res = value;
+                    }
+                }
+                _ => return Err(format!("the argument {} most be a number", i + 1)),
+            }
+        }
+
+        Ok(res.clone())
+    }
+
+    pub fn clamp(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 3 {
+            return Err(format!("expected 3 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1], &vs[2]) {
+            (Value::Int(n), Value::Int(lo), Value::Int(hi)) => {
+                if lo > hi {
+                    return Err(format!(
+                        "the lower bound {} is greater than the upper bound {}",
+                        lo, hi
+                    ));
+                }
+
+                return Ok(Value::Int(*n.max(lo).min(hi)));
+            }
+            (v1, v2, v3) => match (number(v1), number(v2), number(v3)) {
+                (Some(n), Some(lo), Some(hi)) => {
+                    if lo > hi {
+                        return Err(format!(
+                            "the lower bound {} is greater than the upper bound {}",
+                            lo, hi
+                        ));
+                    }
+
+                    return Ok(Value::Float(n.max(lo).min(hi)));
+                }
+                _ => return Err(format!("all arguments most be numbers")),
+            },
+        }
+    }
+
+    pub fn rem(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::Int(lhs), Value::Int(rhs)) => match lhs.checked_rem(*rhs) {
+                Some(n) => return Ok(Value::Int(n)),
+                None => {
+                    if *rhs == 0 {
+                        return Err(format!("cannot divide {} by zero", lhs));
+                    }
+
+                    return Err(format!("integer overflow in {} % {}", lhs, rhs));
+                }
+            },
+            (v1, v2) => match number(v1) {
+                Some(lhs) => match number(v2) {
+                    Some(rhs) => {
+                        if rhs == 0.0 {
+                            return Err(format!("cannot divide {} by zero", lhs));
+                        }
+
+                        return Ok(Value::Float(lhs % rhs));
+                    }
+                    None => return Err(format!("the second argument most be a number")),
+                },
+                None => return Err(format!("the first argument most be a number")),
+            },
+        }
+    }
+
+    pub fn gcd(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::Int(a), Value::Int(b)) => {
+                let res = euclid(a.unsigned_abs(), b.unsigned_abs());
+
+                match i32::try_from(res) {
+                    Ok(n) => return Ok(Value::Int(n)),
+                    Err(_) => return Err(format!("{} is out of the integer range", res)),
+                }
+            }
+            (Value::Int(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be an integer")),
+        }
+    }
+
+    pub fn lcm(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::Int(a), Value::Int(b)) => {
+                if *a == 0 || *b == 0 {
+                    return Ok(Value::Int(0));
+                }
+
+                let (a, b) = (a.unsigned_abs(), b.unsigned_abs());
+                let res = (a / euclid(a, b)) as u64 * b as u64;
+
+                match i32::try_from(res) {
+                    Ok(n) => return Ok(Value::Int(n)),
+                    Err(_) => return Err(format!("{} is out of the integer range", res)),
+                }
+            }
+            (Value::Int(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be an integer")),
+        }
+    }
+
+    pub fn hypot(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(v1) => match number(v1) {
+                Some(x) => match vs.get(1) {
+                    Some(v2) => match number(v2) {
+                        Some(y) => return Ok(Value::Float(x.hypot(y))),
+                        None => return Err(format!("the second argument most be a number")),
+                    },
+                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+                },
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn atan2(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(v1) => match number(v1) {
+                Some(y) => match vs.get(1) {
+                    Some(v2) => match number(v2) {
+                        Some(x) => return Ok(Value::Float(y.atan2(x))),
+                        None => return Err(format!("the second argument most be a number")),
+                    },
+                    None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+                },
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 2 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn to_radians(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => return Ok(Value::Float(n * PI / 180.0)),
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn to_degrees(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => return Ok(Value::Float(n * 180.0 / PI)),
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn sign(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::Int(n) => return Ok(Value::Int(n.signum())),
+                Value::Float(n) => {
+                    if n.is_nan() {
+                        return Err(format!("cannot take the sign of NaN"));
+                    }
+
+                    // `f32::signum` returns 1 for zero, including negative zero
+                    if *n == 0.0 {
+                        return Ok(Value::Float(0.0));
+                    }
+
+                    return Ok(Value::Float(n.signum()));
+                }
+                _ => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn asin(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => {
+                    if !(-1.0..=1.0).contains(&n) {
+                        return Err(format!("the argument most be between -1 and 1"));
+                    }
+
+                    return Ok(Value::Float(n.asin()));
+                }
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn acos(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => {
+                    if !(-1.0..=1.0).contains(&n) {
+                        return Err(format!("the argument most be between -1 and 1"));
+                    }
+
+                    return Ok(Value::Float(n.acos()));
+                }
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn atan(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => return Ok(Value::Float(n.atan())),
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn sinh(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => return Ok(Value::Float(n.sinh())),
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn cosh(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => return Ok(Value::Float(n.cosh())),
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn tanh(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => return Ok(Value::Float(n.tanh())),
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn trunc(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => return Ok(Value::Float(n.trunc())),
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn fract(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match number(value) {
+                Some(n) => return Ok(Value::Float(n.fract())),
+                None => return Err(format!("the first argument most be a number")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn factorial(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(Value::Int(n)) => {
+                if *n < 0 {
+                    return Err(format!("cannot take the factorial of a negative number"));
+                }
+
+                let mut res: i32 = 1;
+
+                for i in 2..=*n {
+                    match res.checked_mul(i) {
+                        Some(r) => res = r,
+                        None => {
+                            return Err(format!("factorial of {} is out of the integer range", n))
+                        }
+                    }
+                }
+
+                return Ok(Value::Int(res));
+            }
+            _ => return Err(format!("the first argument most be an integer")),
+        }
+    }
+
+    pub fn comb(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::Int(n), Value::Int(k)) => {
+                if *n < 0 || *k < 0 {
+                    return Err(format!("the arguments most be non-negative"));
+                }
+
+                if k > n {
+                    return Ok(Value::Int(0));
+                }
+
+                // C(n, i) grows with i up to n / 2, so no step can exceed the result
+                let k = (*k).min(n - k) as i64;
+                let n = *n as i64;
+                let mut res: i64 = 1;
+
+                for i in 0..k {
+                    res = res * (n - i) / (i + 1);
+
+                    if res > i32::MAX as i64 {
+                        return Err(format!("the result is out of the integer range"));
+                    }
+                }
+
+                return Ok(Value::Int(res as i32));
+            }
+            (Value::Int(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be an integer")),
+        }
+    }
+
+    fn integer(n: f32) -> Result<Value, String> {
+        // `i32::MAX as f32` rounds up to 2^31, so the upper bound is exclusive
+        if n >= i32::MIN as f32 && n < i32::MAX as f32 {
+            return Ok(Value::Int(n as i32));
+        }
+
+        Err(format!("{} is out of the integer range", n))
+    }
+
+    fn euclid(mut a: u32, mut b: u32) -> u32 {
+        while b != 0 {
+            let t = b;
+            b = a % b;
+            a = t;
+        }
+
+        a
+    }
+
+    fn number(value: &Value) -> Option<f32> {
+        match value {
+            Value::Int(n) => Some(*n as f32),
+            Value::Float(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use crate::runtime::value::Value;
+
+pub struct Object(BTreeMap<String, Value>);
+
+impl Object {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn exports() -> BTreeMap<String, Value> {
+        let mut object = Object::new();
+
+        // object functions
+        object.declare("keys", Value::BuiltInFn(ak_object::keys));
+        object.declare("values", Value::BuiltInFn(ak_object::values));
+        object.declare("entries", Value::BuiltInFn(ak_object::entries));
+
+        return object.items();
+    }
+
+    pub fn declare(&mut self, name: &str, value: Value) {
+        self.0.insert(String::from(name), value);
+    }
+
+    fn items(self) -> BTreeMap<String, Value> {
+        return self.0;
+    }
+}
+
+// properties are returned in the order they were written
+mod ak_object {
+    use crate::runtime::value::Value;
+
+    pub fn keys(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::Object(props) => {
+                return Ok(Value::List(
+                    props
+                        .iter()
+                        .map(|prop| Value::String(prop.key.to_string()))
+                        .collect(),
+                ))
+            }
+            _ => return Err(format!("the first argument most be an object")),
+        }
+    }
+
+    pub fn values(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::Object(props) => {
+                return Ok(Value::List(
+                    props.iter().map(|prop| prop.value.clone()).collect(),
+                ))
+            }
+            _ => return Err(format!("the first argument most be an object")),
+        }
+    }
+
+    // each entry is a (key, value) tuple
+    pub fn entries(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match &vs[0] {
+            Value::Object(props) => {
+                return Ok(Value::List(
+                    props
+                        .iter()
+                        .map(|prop| {
+                            Value::Tuple(vec![
+                                Value::String(prop.key.to_string()),
+                                prop.value.clone(),
+                            ])
+                        })
+                        .collect(),
+                ))
+            }
+            _ => return Err(format!("the first argument most be an object")),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use crate::runtime::value::Value;
+
+pub struct Random(BTreeMap<String, Value>);
+
+impl Random {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn exports() -> BTreeMap<String, Value> {
+        let mut random = Random::new();
+
+        // random functions
+        random.declare("random", Value::BuiltInFn(ak_random::random));
+        random.declare("range", Value::BuiltInFn(ak_random::range));
+        random.declare("seed", Value::BuiltInFn(ak_random::seed));
+
+        return random.items();
+    }
+
+    pub fn declare(&mut self, name: &str, value: Value) {
+        self.0.insert(String::from(name), value);
+    }
+
+    fn items(self) -> BTreeMap<String, Value> {
+        return self.0;
+    }
+}
+
+mod ak_random {
+    use std::cell::Cell;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::runtime::value::Value;
+
+    thread_local! {
+        static STATE: Cell<u64> = Cell::new(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or(0),
+        );
+    }
+
+    // splitmix64, small and good enough for scripts
+    fn next() -> u64 {
+        STATE.with(|state| {
+            let s = state.get().wrapping_add(0x9E3779B97F4A7C15);
+            state.set(s);
+
+            let mut z = s;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        })
+    }
+
+    pub fn random(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        // the top 24 bits fit exactly in the f32 mantissa, so the result stays below 1
+        let n = (next() >> 40) as f32 / (1u64 << 24) as f32;
+        Ok(Value::Float(n))
+    }
+
+    pub fn range(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(Value::Int(lo)) => match vs.get(1) {
+                Some(Value::Int(hi)) => {
+                    if lo >= hi {
+                        return Err(format!(
+                            "the lower bound {} most be less than the upper bound {}",
+                            lo, hi
+                        ));
+                    }
+
+                    let span = (*hi as i64 - *lo as i64) as u64;
+                    let n = *lo as i64 + (next() % span) as i64;
+                    return Ok(Value::Int(n as i32));
+                }
+                _ => return Err(format!("the second argument most be an integer")),
+            },
+            _ => return Err(format!("the first argument most be an integer")),
+        }
+    }
+
+    pub fn seed(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(Value::Int(n)) => {
+                STATE.with(|state| state.set(*n as u64));
+                return Ok(Value::Null);
+            }
+            _ => return Err(format!("the first argument most be an integer")),
+        }
+    }
+}
@@ -0,0 +1,730 @@
+use std::collections::BTreeMap;
+
+use crate::runtime::value::Value;
+
+pub struct Str(BTreeMap<String, Value>);
+
+impl Str {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn exports() -> BTreeMap<String, Value> {
+        let mut string = Str::new();
+
+        // string functions
+        string.declare("len", Value::BuiltInFn(ak_string::len));
+        string.declare("to_upper", Value::BuiltInFn(ak_string::to_upper));
+        string.declare("to_lower", Value::BuiltInFn(ak_string::to_lower));
+        string.declare("trim", Value::BuiltInFn(ak_string::trim));
+        string.declare("trim_start", Value::BuiltInFn(ak_string::trim_start));
+        string.declare("trim_end", Value::BuiltInFn(ak_string::trim_end));
+        string.declare("replace", Value::BuiltInFn(ak_string::replace));
+        string.declare("split", Value::BuiltInFn(ak_string::split));
+        string.declare("join", Value::BuiltInFn(ak_string::join));
+        string.declare("contains", Value::BuiltInFn(ak_string::contains));
+        string.declare("starts_with", Value::BuiltInFn(ak_string::starts_with));
+        string.declare("ends_with", Value::BuiltInFn(ak_string::ends_with));
+        string.declare("index_of", Value::BuiltInFn(ak_string::index_of));
+        string.declare("substring", Value::BuiltInFn(ak_string::substring));
+        string.declare("slice", Value::BuiltInFn(ak_string::substring));
+        string.declare("repeat", Value::BuiltInFn(ak_string::repeat));
+        string.declare("parse_int", Value::BuiltInFn(ak_string::parse_int));
+        string.declare("parse_float", Value::BuiltInFn(ak_string::parse_float));
+        string.declare("chars", Value::BuiltInFn(ak_string::chars));
+        string.declare("format", Value::BuiltInFn(ak_string::format));
+        string.declare("pad_left", Value::BuiltInFn(ak_string::pad_left));
+        string.declare("pad_right", Value::BuiltInFn(ak_string::pad_right));
+        string.declare("reverse", Value::BuiltInFn(ak_string::reverse));
+        string.declare("capitalize", Value::BuiltInFn(ak_string::capitalize));
+        string.declare("title_case", Value::BuiltInFn(ak_string::title_case));
+        string.declare("lines", Value::BuiltInFn(ak_string::lines));
+        string.declare("count", Value::BuiltInFn(ak_string::count));
+        string.declare("char_at", Value::BuiltInFn(ak_string::char_at));
+        string.declare("code_at", Value::BuiltInFn(ak_string::code_at));
+        string.declare("is_empty", Value::BuiltInFn(ak_string::is_empty));
+        string.declare("is_whitespace", Value::BuiltInFn(ak_string::is_whitespace));
+
+        return string.items();
+    }
+
+    pub fn declare(&mut self, name: &str, value: Value) {
+        self.0.insert(String::from(name), value);
+    }
+
+    fn items(self) -> BTreeMap<String, Value> {
+        return self.0;
+    }
+}
+
+mod ak_string {
+    use crate::runtime::value::Value;
+
+    const MAX_STRING_LEN: usize = 64 * 1024 * 1024;
+
+    pub fn len(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::Int(s.chars().count() as i32)),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn to_upper(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::String(s.to_uppercase())),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn to_lower(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::String(s.to_lowercase())),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn trim(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::String(s.trim().to_string())),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn trim_start(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::String(s.trim_start().to_string())),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn trim_end(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::String(s.trim_end().to_string())),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn replace(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 3 {
+            return Err(format!("expected 3 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1], &vs[2]) {
+            (Value::String(s), Value::String(from), Value::String(to)) => {
+                // an empty pattern would match between every character
+                if from.is_empty() {
+                    return Ok(Value::String(s.to_string()));
+                }
+
+                return Ok(Value::String(s.replace(from, to)));
+            }
+            (Value::String(_), Value::String(_), _) => {
+                return Err(format!("the third argument most be a string"))
+            }
+            (Value::String(_), _, _) => {
+                return Err(format!("the second argument most be a string"))
+            }
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn split(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::String(delimiter)) => {
+                if s.is_empty() {
+                    return Ok(Value::List(vec![Value::String(String::new())]));
+                }
+
+                let mut res = Vec::new();
+
+                // an empty delimiter splits the text into its characters
+                if delimiter.is_empty() {
+                    for c in s.chars() {
+                        res.push(Value::String(c.to_string()));
+                    }
+                } else {
+                    for part in s.split(delimiter.as_str()) {
+                        res.push(Value::String(part.to_string()));
+                    }
+                }
+
+                return Ok(Value::List(res));
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn join(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::List(list), Value::String(separator)) => {
+                let mut parts: Vec<&str> = vec![];
+
+                for (i, item) in list.iter().enumerate() {
+                    match item {
+                        Value::String(s) => parts.push(s),
+                        _ => return Err(format!("the list item at index {} is not a string", i)),
+                    }
+                }
+
+                return Ok(Value::String(parts.join(separator)));
+            }
+            (Value::List(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a list")),
+        }
+    }
+
+    pub fn contains(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::String(pattern)) => {
+                return Ok(Value::Bool(s.contains(pattern.as_str())));
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn starts_with(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::String(pattern)) => {
+                return Ok(Value::Bool(s.starts_with(pattern.as_str())));
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn ends_with(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::String(pattern)) => {
+                return Ok(Value::Bool(s.ends_with(pattern.as_str())));
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn index_of(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 2 {
+            return Err(format!("expected 2 or 3 argument, but found {}", vs.len()));
+        }
+
+        let start = match vs.get(2) {
+            Some(Value::Int(n)) => {
+                if *n < 0 {
+                    return Err(format!("the start offset most be non-negative"));
+                }
+                *n as usize
+            }
+            Some(_) => return Err(format!("the third argument most be an integer")),
+            None => 0,
+        };
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::String(pattern)) => {
+                // indexes are counted in characters, not bytes
+                let offset = match s.char_indices().nth(start) {
+                    Some((i, _)) => i,
+                    None if start == s.chars().count() => s.len(),
+                    None => return Ok(Value::Int(-1)),
+                };
+
+                match s[offset..].find(pattern.as_str()) {
+                    Some(i) => {
+                        return Ok(Value::Int(
+                            (start + s[offset..offset + i].chars().count()) as i32,
+                        ))
+                    }
+                    None => return Ok(Value::Int(-1)),
+                }
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn substring(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 3 {
+            return Err(format!("expected 3 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1], &vs[2]) {
+            (Value::String(s), Value::Int(start), Value::Int(end)) => {
+                let len = s.chars().count();
+                let start = clamp_index(*start, len);
+                let end = clamp_index(*end, len);
+
+                if start >= end {
+                    return Ok(Value::String(String::new()));
+                }
+
+                return Ok(Value::String(
+                    s.chars().skip(start).take(end - start).collect(),
+                ));
+            }
+            (Value::String(_), Value::Int(_), _) => {
+                return Err(format!("the third argument most be an integer"))
+            }
+            (Value::String(_), _, _) => {
+                return Err(format!("the second argument most be an integer"))
+            }
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn repeat(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::Int(count)) => {
+                if *count < 0 {
+                    return Err(format!("the repeat count most be non-negative"));
+                }
+
+                match s.len().checked_mul(*count as usize) {
+                    Some(len) if len <= MAX_STRING_LEN => {
+                        return Ok(Value::String(s.repeat(*count as usize)))
+                    }
+                    _ => {
+                        return Err(format!(
+                            "the result is longer than {} bytes",
+                            MAX_STRING_LEN
+                        ))
+                    }
+                }
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn parse_int(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => match s.trim().parse::<i32>() {
+                    Ok(n) => return Ok(Value::Int(n)),
+                    Err(e) => return Err(format!("cannot parse {:?}: {}", s, e)),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn parse_float(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => match s.trim().parse::<f32>() {
+                    Ok(n) => return Ok(Value::Float(n)),
+                    Err(e) => return Err(format!("cannot parse {:?}: {}", s, e)),
+                },
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn chars(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    let mut chars = vec![];
+
+                    for c in s.chars() {
+                        chars.push(Value::String(c.to_string()));
+                    }
+
+                    return Ok(Value::List(chars));
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn format(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() < 1 {
+            return Err(format!(
+                "expected at least 1 argument, but found {}",
+                vs.len()
+            ));
+        }
+
+        match &vs[0] {
+            Value::String(template) => {
+                let mut res = String::new();
+                let mut args = vs[1..].iter();
+                let mut chars = template.chars().peekable();
+
+                while let Some(c) = chars.next() {
+                    match c {
+                        '{' if chars.peek() == Some(&'{') => {
+                            chars.next();
+                            res.push('{');
+                        }
+                        '}' if chars.peek() == Some(&'}') => {
+                            chars.next();
+                            res.push('}');
+                        }
+                        '{' if chars.peek() == Some(&'}') => {
+                            chars.next();
+                            match args.next() {
+                                Some(value) => res.push_str(&value.to_string()),
+                                None => {
+                                    return Err(format!(
+                                        "not enough arguments for the format string"
+                                    ))
+                                }
+                            }
+                        }
+                        c => res.push(c),
+                    }
+                }
+
+                if args.next().is_some() {
+                    return Err(format!("too many arguments for the format string"));
+                }
+
+                return Ok(Value::String(res));
+            }
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn pad_left(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 3 {
+            return Err(format!("expected 3 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1], &vs[2]) {
+            (Value::String(s), Value::Int(width), Value::String(fill)) => {
+                let mut fill_chars = fill.chars();
+
+                match (fill_chars.next(), fill_chars.next()) {
+                    (Some(c), None) => {
+                        let len = s.chars().count();
+                        let width = (*width).max(0) as usize;
+
+                        if len >= width {
+                            return Ok(Value::String(s.to_string()));
+                        }
+
+                        let padding = c.to_string().repeat(width - len);
+                        return Ok(Value::String(padding + s));
+                    }
+                    _ => return Err(format!("the fill most be a single character")),
+                }
+            }
+            (Value::String(_), Value::Int(_), _) => {
+                return Err(format!("the third argument most be a string"))
+            }
+            (Value::String(_), _, _) => {
+                return Err(format!("the second argument most be an integer"))
+            }
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn pad_right(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 3 || vs.len() < 3 {
+            return Err(format!("expected 3 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1], &vs[2]) {
+            (Value::String(s), Value::Int(width), Value::String(fill)) => {
+                let mut fill_chars = fill.chars();
+
+                match (fill_chars.next(), fill_chars.next()) {
+                    (Some(c), None) => {
+                        let len = s.chars().count();
+                        let width = (*width).max(0) as usize;
+
+                        if len >= width {
+                            return Ok(Value::String(s.to_string()));
+                        }
+
+                        let padding = c.to_string().repeat(width - len);
+                        return Ok(Value::String(s.to_string() + &padding));
+                    }
+                    _ => return Err(format!("the fill most be a single character")),
+                }
+            }
+            (Value::String(_), Value::Int(_), _) => {
+                return Err(format!("the third argument most be a string"))
+            }
+            (Value::String(_), _, _) => {
+                return Err(format!("the second argument most be an integer"))
+            }
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn reverse(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::String(s.chars().rev().collect())),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn capitalize(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::String(capitalize_word(s))),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn title_case(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    let mut res = String::new();
+                    let mut word = String::new();
+
+                    // whitespace is copied as is, so runs of spaces are kept
+                    for c in s.chars() {
+                        if c.is_whitespace() {
+                            res.push_str(&capitalize_word(&word));
+                            res.push(c);
+                            word.clear();
+                        } else {
+                            word.push(c);
+                        }
+                    }
+                    res.push_str(&capitalize_word(&word));
+
+                    return Ok(Value::String(res));
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn lines(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    return Ok(Value::List(
+                        s.lines()
+                            .map(|line| Value::String(line.to_string()))
+                            .collect(),
+                    ))
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn count(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::String(pattern)) => {
+                if pattern.is_empty() {
+                    return Err(format!("the pattern most not be empty"));
+                }
+
+                return Ok(Value::Int(s.matches(pattern.as_str()).count() as i32));
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be a string")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn char_at(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::Int(index)) => {
+                let c = nth_char(s, *index)?;
+                return Ok(Value::String(c.to_string()));
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn code_at(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(s), Value::Int(index)) => {
+                let c = nth_char(s, *index)?;
+                return Ok(Value::Int(c as i32));
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be an integer")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+
+    pub fn is_empty(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => return Ok(Value::Bool(s.is_empty())),
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    pub fn is_whitespace(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 || vs.len() < 1 {
+            return Err(format!("expected 1 argument, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(value) => match value {
+                Value::String(s) => {
+                    return Ok(Value::Bool(
+                        !s.is_empty() && s.chars().all(char::is_whitespace),
+                    ))
+                }
+                _ => return Err(format!("the first argument most be a string")),
+            },
+            None => return Err(format!("expected 1 argument, but found {}", vs.len())),
+        }
+    }
+
+    // negative indexes count from the end, out of range indexes are clamped
+    fn clamp_index(index: i32, len: usize) -> usize {
+        if index < 0 {
+            return len.saturating_sub(index.unsigned_abs() as usize);
+        }
+
+        (index as usize).min(len)
+    }
+
+    fn capitalize_word(word: &str) -> String {
+        let mut chars = word.chars();
+
+        match chars.next() {
+            Some(first) => {
+                first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase()
+            }
+            None => String::new(),
+        }
+    }
+
+    // indexes are counted in characters, like len and substring
+    fn nth_char(s: &String, index: i32) -> Result<char, String> {
+        let len = s.chars().count();
+
+        if index < 0 || index as usize >= len {
+            return Err(format!(
+                "index {} is out of range for a string of length {}",
+                index, len
+            ));
+        }
+
+        Ok(s.chars().nth(index as usize).unwrap())
+    }
+}
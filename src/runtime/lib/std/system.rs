@@ -1,140 +1,266 @@
-use std::collections::BTreeMap;
-
-use crate::runtime::value::Value;
-
-pub struct System(BTreeMap<String, Value>);
-
-impl System {
-    pub fn new() -> Self {
-        Self(BTreeMap::new())
-    }
-
-    pub fn exports() -> BTreeMap<String, Value> {
-        let mut system = System::new();
-
-        // system functions
-        system.declare("platform", Value::BuiltInFn(ak_system::_platform));
-        system.declare("free_mem", Value::BuiltInFn(ak_system::_free_mem));
-        system.declare("total_mem", Value::BuiltInFn(ak_system::_total_mem));
-        system.declare("free_disk", Value::BuiltInFn(ak_system::_free_disk));
-        system.declare("total_disk", Value::BuiltInFn(ak_system::_total_disk));
-        system.declare("cpu_speed", Value::BuiltInFn(ak_system::_cpu_speed));
-        system.declare("cpus", Value::BuiltInFn(ak_system::_cpus));
-        system.declare("arch", Value::BuiltInFn(ak_system::_arch));
-        system.declare("version", Value::BuiltInFn(ak_system::_version));
-        system.declare("processes", Value::BuiltInFn(ak_system::_processes));
-        system.declare("family", Value::BuiltInFn(ak_system::_family));
-
-        return system.items();
-    }
-
-    pub fn declare(&mut self, name: &str, value: Value) {
-        self.0.insert(String::from(name), value);
-    }
-
-    pub fn items(self) -> BTreeMap<String, Value> {
-        return self.0;
-    }
-}
-
-pub mod ak_system {
-    use std::env;
-
-    use crate::runtime::value::Value;
-
-    pub fn _platform(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        Ok(Value::String(env::consts::OS.to_string()))
-    }
-
-    pub fn _free_mem(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        let mem_info = sys_info::mem_info().unwrap();
-        Ok(Value::Int(mem_info.free as i32))
-    }
-
-    pub fn _total_mem(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        let mem_info = sys_info::mem_info().unwrap();
-        Ok(Value::Int(mem_info.total as i32))
-    }
-
-    pub fn _total_disk(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        let disk_info = sys_info::disk_info().unwrap();
-        Ok(Value::Int(disk_info.total as i32))
-    }
-
-    pub fn _free_disk(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        let disk_info = sys_info::disk_info().unwrap();
-        Ok(Value::Int(disk_info.free as i32))
-    }
-
-    pub fn _cpus(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        let cpu_num = sys_info::cpu_num().unwrap();
-        Ok(Value::Int(cpu_num as i32))
-    }
-
-    pub fn _cpu_speed(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        let cpu_speed = sys_info::cpu_speed().unwrap();
-        Ok(Value::Int(cpu_speed as i32))
-    }
-
-    pub fn _version(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        let version = sys_info::os_release().unwrap();
-        Ok(Value::String(version))
-    }
-
-    pub fn _processes(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        let processes = sys_info::proc_total().unwrap();
-        Ok(Value::Int(processes as i32))
-    }
-
-    pub fn _arch(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        Ok(Value::String(env::consts::ARCH.to_string()))
-    }
-
-    pub fn _family(vs: Vec<Value>) -> Result<Value, String> {
-        if vs.len() > 0 {
-            return Err(format!("expected 0 arguments, but found {}", vs.len()));
-        }
-
-        Ok(Value::String(env::consts::FAMILY.to_string()))
-    }
-}
+use std::collections::BTreeMap;
+
+use crate::runtime::value::Value;
+
+pub struct System(BTreeMap<String, Value>);
+
+impl System {
+    pub fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn exports() -> BTreeMap<String, Value> {
+        let mut system = System::new();
+
+        // system functions
+        system.declare("platform", Value::BuiltInFn(ak_system::_platform));
+        system.declare("free_mem", Value::BuiltInFn(ak_system::_free_mem));
+        system.declare("total_mem", Value::BuiltInFn(ak_system::_total_mem));
+        system.declare("free_disk", Value::BuiltInFn(ak_system::_free_disk));
+        system.declare("total_disk", Value::BuiltInFn(ak_system::_total_disk));
+        system.declare("cpu_speed", Value::BuiltInFn(ak_system::_cpu_speed));
+        system.declare("cpus", Value::BuiltInFn(ak_system::_cpus));
+        system.declare("arch", Value::BuiltInFn(ak_system::_arch));
+        system.declare("version", Value::BuiltInFn(ak_system::_version));
+        system.declare("processes", Value::BuiltInFn(ak_system::_processes));
+        system.declare("family", Value::BuiltInFn(ak_system::_family));
+        system.declare("args", Value::BuiltInFn(ak_system::_args));
+        system.declare("exit", Value::BuiltInFn(ak_system::_exit));
+        system.declare("hostname", Value::BuiltInFn(ak_system::_hostname));
+        system.declare("uptime", Value::BuiltInFn(ak_system::_uptime));
+        system.declare("pid", Value::BuiltInFn(ak_system::_pid));
+        system.declare("exec", Value::BuiltInFn(ak_system::_exec));
+
+        return system.items();
+    }
+
+    pub fn declare(&mut self, name: &str, value: Value) {
+        self.0.insert(String::from(name), value);
+    }
+
+    pub fn items(self) -> BTreeMap<String, Value> {
+        return self.0;
+    }
+}
+
+pub mod ak_system {
+    use std::{env, process};
+
+    use crate::runtime::value::{KeyValue, Value};
+
+    pub fn _platform(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        Ok(Value::String(env::consts::OS.to_string()))
+    }
+
+    pub fn _free_mem(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let mem_info = sys_info::mem_info().unwrap();
+        Ok(Value::Int(mem_info.free as i32))
+    }
+
+    pub fn _total_mem(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let mem_info = sys_info::mem_info().unwrap();
+        Ok(Value::Int(mem_info.total as i32))
+    }
+
+    pub fn _total_disk(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let disk_info = sys_info::disk_info().unwrap();
+        Ok(Value::Int(disk_info.total as i32))
+    }
+
+    pub fn _free_disk(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let disk_info = sys_info::disk_info().unwrap();
+        Ok(Value::Int(disk_info.free as i32))
+    }
+
+    pub fn _cpus(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let cpu_num = sys_info::cpu_num().unwrap();
+        Ok(Value::Int(cpu_num as i32))
+    }
+
+    pub fn _cpu_speed(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let cpu_speed = sys_info::cpu_speed().unwrap();
+        Ok(Value::Int(cpu_speed as i32))
+    }
+
+    pub fn _version(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let version = sys_info::os_release().unwrap();
+        Ok(Value::String(version))
+    }
+
+    pub fn _processes(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let processes = sys_info::proc_total().unwrap();
+        Ok(Value::Int(processes as i32))
+    }
+
+    pub fn _arch(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        Ok(Value::String(env::consts::ARCH.to_string()))
+    }
+
+    pub fn _family(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        Ok(Value::String(env::consts::FAMILY.to_string()))
+    }
+
+    // the script path and its arguments, without the interpreter itself
+    pub fn _args(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let args = env::args().skip(1).map(Value::String).collect();
+        Ok(Value::List(args))
+    }
+
+    // exit(code) stops the program, the code defaults to 0
+    pub fn _exit(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 1 {
+            return Err(format!("expected 0 or 1 arguments, but found {}", vs.len()));
+        }
+
+        match vs.get(0) {
+            Some(Value::Int(code)) => process::exit(*code),
+            Some(_) => return Err(format!("the first argument most be an integer")),
+            None => process::exit(0),
+        }
+    }
+
+    pub fn _hostname(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        match sys_info::hostname() {
+            Ok(hostname) if !hostname.is_empty() => Ok(Value::String(hostname)),
+            Ok(_) => Err(format!("unable to get the hostname")),
+            Err(e) => Err(format!("unable to get the hostname: {}", e)),
+        }
+    }
+
+    // uptime in seconds
+    #[cfg(not(windows))]
+    pub fn _uptime(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        let boottime = sys_info::boottime().map_err(|e| e.to_string())?;
+
+        // linux reports the uptime directly, other systems report the boot time
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let uptime = boottime.tv_sec as u64;
+
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let uptime = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| e.to_string())?
+            .as_secs()
+            .saturating_sub(boottime.tv_sec as u64);
+
+        Ok(Value::Int(uptime as i32))
+    }
+
+    #[cfg(windows)]
+    pub fn _uptime(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        Err(format!("uptime is not supported on this platform"))
+    }
+
+    pub fn _pid(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 0 {
+            return Err(format!("expected 0 arguments, but found {}", vs.len()));
+        }
+
+        Ok(Value::Int(process::id() as i32))
+    }
+
+    // runs a program and captures its output instead of printing it
+    pub fn _exec(vs: Vec<Value>) -> Result<Value, String> {
+        if vs.len() > 2 || vs.len() < 2 {
+            return Err(format!("expected 2 argument, but found {}", vs.len()));
+        }
+
+        match (&vs[0], &vs[1]) {
+            (Value::String(program), Value::List(list)) => {
+                let mut args = Vec::new();
+
+                for (i, item) in list.iter().enumerate() {
+                    match item {
+                        Value::String(arg) => args.push(arg),
+                        _ => return Err(format!("the argument at index {} most be a string", i)),
+                    }
+                }
+
+                match process::Command::new(program).args(args).output() {
+                    Ok(output) => Ok(Value::Object(vec![
+                        KeyValue {
+                            key: "stdout".to_string(),
+                            value: Value::String(
+                                String::from_utf8_lossy(&output.stdout).to_string(),
+                            ),
+                        },
+                        KeyValue {
+                            key: "stderr".to_string(),
+                            value: Value::String(
+                                String::from_utf8_lossy(&output.stderr).to_string(),
+                            ),
+                        },
+                        // -1 when the program was stopped by a signal
+                        KeyValue {
+                            key: "status".to_string(),
+                            value: Value::Int(output.status.code().unwrap_or(-1)),
+                        },
+                    ])),
+                    Err(e) => Err(format!("unable to run {}: {}", program, e)),
+                }
+            }
+            (Value::String(_), _) => return Err(format!("the second argument most be a list")),
+            _ => return Err(format!("the first argument most be a string")),
+        }
+    }
+}
@@ -1,314 +1,487 @@
-use std::collections::BTreeMap;
-use std::fmt::Display;
-use std::ops::{Add, Div, Mul, Not, Sub};
-
-use crate::ast::{Arg, Block};
-
-use super::{Simple, Type};
-
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub enum Value {
-    Null,
-    Int(i32),
-    Float(f32),
-    String(String),
-    Bool(bool),
-    List(Vec<Value>),
-    Object(Vec<KeyValue>),
-    BuiltInFn(fn(Vec<Value>) -> Result<Value, String>),
-    BuiltInMethod(
-        fn(Vec<Value>, Value) -> Result<Value, String>,
-        Option<Box<Value>>,
-    ),
-    Func(Vec<Arg>, Option<Type>, Block),
-    Module(BTreeMap<String, Value>),
-    Tuple(Vec<Value>),
-    Type(String, Type),
-}
-
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct KeyValue {
-    pub key: String,
-    pub value: Value,
-}
-
-pub fn check_list_items(list: &Vec<Value>) -> Result<(), String> {
-    if let Some(value) = list.get(0) {
-        for item in list {
-            if Type::from(item) != Type::from(value) {
-                return Err(format!(
-                    "expected {}, found {}. help: all list items most have same datatype",
-                    Type::simple(value),
-                    Type::simple(item)
-                ));
-            }
-        }
-    }
-
-    Ok(())
-}
-
-pub fn value_list(values: Vec<Value>) -> String {
-    let mut res = String::new();
-
-    for (i, value) in values.iter().enumerate() {
-        if i != 0 {
-            res.push_str(", ");
-        }
-        res.push_str(&value.to_string());
-    }
-
-    res
-}
-
-pub fn key_value(obj: Vec<KeyValue>) -> String {
-    let mut res = String::new();
-
-    for prop in obj {
-        res.push('\t');
-        res.push_str(&prop.key);
-        res.push_str(": ");
-        res.push_str(&prop.value.to_string());
-        res.push(',');
-        res.push('\n');
-    }
-
-    res
-}
-
-#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash)]
-pub enum BuiltinType {
-    Null,
-    Int,
-    Float,
-    Bool,
-    String,
-    List(Box<Type>),
-    Tuple(Vec<Type>),
-    Fn(Vec<Type>, Box<Type>),
-}
-
-impl From<&Value> for Value {
-    fn from(value: &Value) -> Self {
-        match value {
-            Value::Null => Value::Null,
-            Value::Int(n) => Value::Int(*n),
-            Value::Float(n) => Value::Float(*n),
-            Value::String(s) => Value::String(s.to_string()),
-            Value::Bool(b) => Value::Bool(*b),
-            Value::List(l) => Value::List(l.to_vec()),
-            Value::BuiltInFn(f) => Value::BuiltInFn(*f),
-            Value::BuiltInMethod(f, this) => Value::BuiltInMethod(*f, this.clone()),
-            Value::Func(args, ret_type, block) => {
-                Value::Func(args.to_vec(), ret_type.clone(), block.to_vec())
-            }
-            Value::Object(props) => Value::Object(props.to_vec()),
-            Value::Module(items) => Value::Module(items.to_owned()),
-            Value::Tuple(t) => Value::Tuple(t.to_vec()),
-            Value::Type(n, t) => Value::Type(n.clone(), t.clone()),
-        }
-    }
-}
-
-impl Display for Value {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Value::Null => write!(f, "null"),
-            Value::Int(n) => write!(f, "{}", n),
-            Value::Float(n) => write!(f, "{}", n),
-            Value::String(s) => write!(f, "{}", s),
-            Value::Bool(b) => write!(f, "{}", b),
-            Value::BuiltInFn(_) => write!(f, "function"),
-            Value::List(v) => write!(f, "[{}]", value_list(v.to_vec())),
-            Value::BuiltInMethod(..) => write!(f, "function"),
-            Value::Func(..) => write!(f, "function"),
-            Value::Object(obj) => write!(f, "{{\n{}}}", key_value(obj.to_vec())),
-            Value::Module(_) => write!(f, "module"),
-            Value::Tuple(t) => write!(f, "({})", value_list(t.to_vec())),
-            Value::Type(..) => write!(f, "type"),
-        }
-    }
-}
-
-impl From<Type> for Value {
-    fn from(value: Type) -> Self {
-        match value {
-            Type::Alias(_) => todo!(),
-            Type::Builtin(t) => match t {
-                BuiltinType::Null => Value::Null,
-                BuiltinType::Int => Value::Int(i32::default()),
-                BuiltinType::Float => Value::Float(f32::default()),
-                BuiltinType::Bool => Value::Bool(bool::default()),
-                BuiltinType::String => Value::String(String::default()),
-                BuiltinType::List(_) => Value::List(vec![]),
-                BuiltinType::Tuple(_) => Value::Tuple(vec![]),
-                BuiltinType::Fn(_, ret_type) => Value::Func(vec![], Some(*ret_type), vec![]),
-            },
-        }
-    }
-}
-
-impl Display for BuiltinType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BuiltinType::Null => write!(f, "null"),
-            BuiltinType::Int => write!(f, "int"),
-            BuiltinType::Float => write!(f, "float"),
-            BuiltinType::Bool => write!(f, "bool"),
-            BuiltinType::String => write!(f, "string"),
-            BuiltinType::List(t) => {
-                let datatype = *t.clone();
-
-                return write!(f, "{}[]", datatype.to_string());
-            }
-            BuiltinType::Tuple(types) => {
-                let mut res = String::new();
-
-                for (i, t) in types.iter().enumerate() {
-                    if i >= 1 {
-                        res.push_str(", ");
-                    }
-                    res.push_str(&String::from(t.clone()));
-                }
-
-                return write!(f, "({})", res);
-            }
-            BuiltinType::Fn(args, ret_type) => {
-                let mut args_types = String::new();
-                let ret_type = *ret_type.clone();
-
-                for (i, arg) in args.iter().enumerate() {
-                    if i >= 1 {
-                        args_types.push_str(", ");
-                    }
-                    args_types.push_str(&String::from(arg.clone()));
-                }
-
-                return write!(f, "fn({}) -> {}", args_types, ret_type.to_string());
-            }
-        }
-    }
-}
-
-impl Not for Value {
-    type Output = Result<Value, String>;
-
-    fn not(self) -> Self::Output {
-        match &self {
-            Value::Bool(b) => Ok(Value::Bool(!b)),
-            _ => Err(format!(
-                "cannot apply unary operator '!' to type {}",
-                Type::simple(&self)
-            )),
-        }
-    }
-}
-
-impl Add for &Value {
-    type Output = Result<Value, String>;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        match self {
-            Value::Int(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::Int(lhs + rhs)),
-                Value::Float(rhs) => Ok(Value::Float(*lhs as f32 + rhs)),
-                Value::String(rhs) => Ok(Value::String(lhs.to_string() + &rhs)),
-                other => Err(format!("cannot add int to {}", Type::simple(other))),
-            },
-            Value::Float(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::Float(lhs + *rhs as f32)),
-                Value::Float(rhs) => Ok(Value::Float(lhs + rhs)),
-                Value::String(rhs) => Ok(Value::String(lhs.to_string() + &rhs)),
-                other => Err(format!("cannot add float to {}", Type::simple(other))),
-            },
-            Value::String(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::String(lhs.to_owned() + &rhs.to_string().to_owned())),
-                Value::Float(rhs) => {
-                    Ok(Value::String(lhs.to_owned() + &rhs.to_string().to_owned()))
-                }
-                Value::String(rhs) => Ok(Value::String(lhs.to_owned() + rhs)),
-                Value::List(rhs) => Ok(Value::String(
-                    lhs.to_owned() + Value::List(rhs.to_owned()).to_string().as_str(),
-                )),
-                other => Err(format!("cannot add stirng to {}", Type::simple(other))),
-            },
-            other => Err(format!(
-                "cannot add {} to {}",
-                Type::simple(other),
-                Type::simple(rhs)
-            )),
-        }
-    }
-}
-
-impl Mul for &Value {
-    type Output = Result<Value, String>;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        match self {
-            Value::Int(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::Int(lhs * rhs)),
-                Value::Float(rhs) => Ok(Value::Float(*lhs as f32 * rhs)),
-                other => Err(format!("cannot mul int to {}", Type::simple(other))),
-            },
-            Value::Float(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::Float(lhs * *rhs as f32)),
-                Value::Float(rhs) => Ok(Value::Float(lhs * rhs)),
-                other => Err(format!("cannot mul float to {}", Type::simple(other))),
-            },
-            other => Err(format!(
-                "cannot mul {} to {}",
-                Type::simple(other),
-                Type::simple(rhs)
-            )),
-        }
-    }
-}
-
-impl Div for &Value {
-    type Output = Result<Value, String>;
-
-    fn div(self, rhs: Self) -> Self::Output {
-        match self {
-            Value::Int(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::Int(lhs / rhs)),
-                Value::Float(rhs) => Ok(Value::Float(*lhs as f32 / rhs)),
-                other => Err(format!("cannot div int to {}", Type::simple(other))),
-            },
-            Value::Float(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::Float(lhs / *rhs as f32)),
-                Value::Float(rhs) => Ok(Value::Float(lhs / rhs)),
-                other => Err(format!("cannot div float to {}", Type::simple(other))),
-            },
-            other => Err(format!(
-                "cannot div {} to {}",
-                Type::simple(other),
-                Type::simple(rhs)
-            )),
-        }
-    }
-}
-
-impl Sub for &Value {
-    type Output = Result<Value, String>;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        match self {
-            Value::Int(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::Int(lhs - rhs)),
-                Value::Float(rhs) => Ok(Value::Float(*lhs as f32 - rhs)),
-                other => Err(format!("cannot sub float to {}", Type::simple(other))),
-            },
-            Value::Float(lhs) => match rhs {
-                Value::Int(rhs) => Ok(Value::Float(lhs - *rhs as f32)),
-                Value::Float(rhs) => Ok(Value::Float(lhs - rhs)),
-                other => Err(format!("cannot sub float to {}", Type::simple(other))),
-            },
-            other => Err(format!(
-                "cannot sub {} to {}",
-                Type::simple(other),
-                Type::simple(rhs)
-            )),
-        }
-    }
-}
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::fs::File;
+use std::io::BufReader;
+use std::ops::{Add, Div, Mul, Neg, Not, Sub};
+use std::sync::{Arc, Mutex};
+
+use crate::ast::{Arg, Block};
+
+use super::{ScopeStack, Simple, StdLib, Type};
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Value {
+    Null,
+    Int(i32),
+    Float(f32),
+    String(String),
+    Bool(bool),
+    List(Vec<Value>),
+    Object(Vec<KeyValue>),
+    Map(BTreeMap<String, Value>),
+    BuiltInFn(fn(Vec<Value>) -> Result<Value, String>),
+    BuiltInMethod(
+        fn(Vec<Value>, Value) -> Result<Value, String>,
+        Option<Box<Value>>,
+    ),
+    // builtins that call back into the evaluator, like collections::map
+    HostFn(
+        fn(
+            &mut ScopeStack,
+            Vec<Value>,
+            &HashMap<String, HashMap<String, Value>>,
+        ) -> Result<Value, String>,
+    ),
+    Func(Vec<Arg>, Option<Type>, Block, Captured),
+    Module(BTreeMap<String, Value>),
+    Tuple(Vec<Value>),
+    Type(String, Type),
+    FileHandle(FileHandle),
+}
+
+// an open file, `None` once it is closed
+#[derive(Debug, Clone)]
+pub struct FileHandle(pub Arc<Mutex<Option<BufReader<File>>>>);
+
+// handles are only equal to themselves
+impl PartialEq for FileHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for FileHandle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+
+        None
+    }
+}
+
+// the scopes a function was defined in, shared so captured variables can be mutated
+#[derive(Clone)]
+pub struct Captured(pub ScopeStack);
+
+impl std::fmt::Debug for Captured {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Captured")
+    }
+}
+
+// captures are only equal when they share the same scopes
+impl PartialEq for Captured {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 .0.len() == other.0 .0.len()
+            && self
+                .0
+                 .0
+                .iter()
+                .zip(&other.0 .0)
+                .all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
+
+impl PartialOrd for Captured {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct KeyValue {
+    pub key: String,
+    pub value: Value,
+}
+
+pub fn check_list_items(list: &Vec<Value>) -> Result<(), String> {
+    if let Some(value) = list.get(0) {
+        for item in list {
+            if Type::from(item) != Type::from(value) {
+                return Err(format!(
+                    "expected {}, found {}. help: all list items most have same datatype",
+                    Type::simple(value),
+                    Type::simple(item)
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// like ==, but objects compare by their keys regardless of order and ints equal floats
+pub fn structural_eq(lhs: &Value, rhs: &Value) -> bool {
+    match (lhs, rhs) {
+        (Value::Int(a), Value::Float(b)) | (Value::Float(b), Value::Int(a)) => *a as f32 == *b,
+        (Value::List(a), Value::List(b)) | (Value::Tuple(a), Value::Tuple(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| structural_eq(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter().all(|prop| {
+                    b.iter().any(|other| {
+                        other.key == prop.key && structural_eq(&other.value, &prop.value)
+                    })
+                })
+        }
+        (Value::Map(a), Value::Map(b)) => {
+            a.len() == b.len()
+                && a.iter().all(|(key, value)| match b.get(key) {
+                    Some(other) => structural_eq(value, other),
+                    None => false,
+                })
+        }
+        _ => lhs == rhs,
+    }
+}
+
+// ordering for `<`, `>`, `<=` and `>=`, `None` when a float is NaN
+pub fn compare(lhs: &Value, rhs: &Value) -> Result<Option<Ordering>, String> {
+    match (lhs, rhs) {
+        (Value::Int(a), Value::Int(b)) => Ok(Some(a.cmp(b))),
+        (Value::Int(a), Value::Float(b)) => Ok((*a as f32).partial_cmp(b)),
+        (Value::Float(a), Value::Int(b)) => Ok(a.partial_cmp(&(*b as f32))),
+        (Value::Float(a), Value::Float(b)) => Ok(a.partial_cmp(b)),
+        (Value::String(a), Value::String(b)) => Ok(Some(a.cmp(b))),
+        _ => Err(format!(
+            "cannot compare {} with {}",
+            Type::simple(lhs),
+            Type::simple(rhs)
+        )),
+    }
+}
+
+pub fn value_list(values: Vec<Value>) -> String {
+    let mut res = String::new();
+
+    for (i, value) in values.iter().enumerate() {
+        if i != 0 {
+            res.push_str(", ");
+        }
+        res.push_str(&value.to_string());
+    }
+
+    res
+}
+
+pub fn key_value(obj: Vec<KeyValue>) -> String {
+    let mut res = String::new();
+
+    for prop in obj {
+        res.push('\t');
+        res.push_str(&prop.key);
+        res.push_str(": ");
+        res.push_str(&prop.value.to_string());
+        res.push(',');
+        res.push('\n');
+    }
+
+    res
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash)]
+pub enum BuiltinType {
+    Null,
+    Int,
+    Float,
+    Bool,
+    String,
+    List(Box<Type>),
+    Tuple(Vec<Type>),
+    Map,
+    File,
+    Fn(Vec<Type>, Box<Type>),
+}
+
+impl From<&Value> for Value {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Null => Value::Null,
+            Value::Int(n) => Value::Int(*n),
+            Value::Float(n) => Value::Float(*n),
+            Value::String(s) => Value::String(s.to_string()),
+            Value::Bool(b) => Value::Bool(*b),
+            Value::List(l) => Value::List(l.to_vec()),
+            Value::BuiltInFn(f) => Value::BuiltInFn(*f),
+            Value::BuiltInMethod(f, this) => Value::BuiltInMethod(*f, this.clone()),
+            Value::HostFn(f) => Value::HostFn(*f),
+            Value::Func(args, ret_type, block, captured) => Value::Func(
+                args.to_vec(),
+                ret_type.clone(),
+                block.to_vec(),
+                captured.clone(),
+            ),
+            Value::Object(props) => Value::Object(props.to_vec()),
+            Value::Map(items) => Value::Map(items.to_owned()),
+            Value::Module(items) => Value::Module(items.to_owned()),
+            Value::Tuple(t) => Value::Tuple(t.to_vec()),
+            Value::Type(n, t) => Value::Type(n.clone(), t.clone()),
+            Value::FileHandle(handle) => Value::FileHandle(handle.clone()),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Float(n) => write!(f, "{}", n),
+            Value::String(s) => write!(f, "{}", s),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::BuiltInFn(_) => write!(f, "function"),
+            Value::List(v) => write!(f, "[{}]", value_list(v.to_vec())),
+            Value::BuiltInMethod(..) => write!(f, "function"),
+            Value::HostFn(_) => write!(f, "function"),
+            Value::Func(..) => write!(f, "function"),
+            Value::Object(obj) => write!(f, "{{\n{}}}", key_value(obj.to_vec())),
+            Value::Map(items) => {
+                let props = items
+                    .iter()
+                    .map(|(key, value)| KeyValue {
+                        key: key.to_string(),
+                        value: value.clone(),
+                    })
+                    .collect();
+
+                write!(f, "{{\n{}}}", key_value(props))
+            }
+            Value::Module(_) => write!(f, "module"),
+            Value::Tuple(t) => write!(f, "({})", value_list(t.to_vec())),
+            Value::Type(..) => write!(f, "type"),
+            Value::FileHandle(_) => write!(f, "file"),
+        }
+    }
+}
+
+impl From<Type> for Value {
+    fn from(value: Type) -> Self {
+        match value {
+            Type::Alias(_) => todo!(),
+            Type::Builtin(t) => match t {
+                BuiltinType::Null => Value::Null,
+                BuiltinType::Int => Value::Int(i32::default()),
+                BuiltinType::Float => Value::Float(f32::default()),
+                BuiltinType::Bool => Value::Bool(bool::default()),
+                BuiltinType::String => Value::String(String::default()),
+                BuiltinType::List(_) => Value::List(vec![]),
+                BuiltinType::Tuple(_) => Value::Tuple(vec![]),
+                BuiltinType::Map => Value::Map(BTreeMap::new()),
+                BuiltinType::File => Value::FileHandle(FileHandle(Arc::new(Mutex::new(None)))),
+                BuiltinType::Fn(_, ret_type) => Value::Func(
+                    vec![],
+                    Some(*ret_type),
+                    vec![],
+                    Captured(ScopeStack::new(vec![Arc::new(Mutex::new(
+                        StdLib::exports(),
+                    ))])),
+                ),
+            },
+        }
+    }
+}
+
+impl Display for BuiltinType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuiltinType::Null => write!(f, "null"),
+            BuiltinType::Int => write!(f, "int"),
+            BuiltinType::Float => write!(f, "float"),
+            BuiltinType::Bool => write!(f, "bool"),
+            BuiltinType::String => write!(f, "string"),
+            BuiltinType::Map => write!(f, "map"),
+            BuiltinType::File => write!(f, "file"),
+            BuiltinType::List(t) => {
+                let datatype = *t.clone();
+
+                return write!(f, "{}[]", datatype.to_string());
+            }
+            BuiltinType::Tuple(types) => {
+                let mut res = String::new();
+
+                for (i, t) in types.iter().enumerate() {
+                    if i >= 1 {
+                        res.push_str(", ");
+                    }
+                    res.push_str(&String::from(t.clone()));
+                }
+
+                return write!(f, "({})", res);
+            }
+            BuiltinType::Fn(args, ret_type) => {
+                let mut args_types = String::new();
+                let ret_type = *ret_type.clone();
+
+                for (i, arg) in args.iter().enumerate() {
+                    if i >= 1 {
+                        args_types.push_str(", ");
+                    }
+                    args_types.push_str(&String::from(arg.clone()));
+                }
+
+                return write!(f, "fn({}) -> {}", args_types, ret_type.to_string());
+            }
+        }
+    }
+}
+
+impl Not for Value {
+    type Output = Result<Value, String>;
+
+    fn not(self) -> Self::Output {
+        match &self {
+            Value::Bool(b) => Ok(Value::Bool(!b)),
+            _ => Err(format!(
+                "cannot apply unary operator '!' to type {}",
+                Type::simple(&self)
+            )),
+        }
+    }
+}
+
+impl Neg for Value {
+    type Output = Result<Value, String>;
+
+    fn neg(self) -> Self::Output {
+        match &self {
+            Value::Int(n) => match n.checked_neg() {
+                Some(n) => Ok(Value::Int(n)),
+                None => Err(format!("integer overflow in -({})", n)),
+            },
+            Value::Float(n) => Ok(Value::Float(-n)),
+            _ => Err(format!(
+                "cannot apply unary operator '-' to type {}",
+                Type::simple(&self)
+            )),
+        }
+    }
+}
+
+impl Add for &Value {
+    type Output = Result<Value, String>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match self {
+            Value::Int(lhs) => match rhs {
+                Value::Int(rhs) => match lhs.checked_add(*rhs) {
+                    Some(n) => Ok(Value::Int(n)),
+                    None => Err(format!("integer overflow in {} + {}", lhs, rhs)),
+                },
+                Value::Float(rhs) => Ok(Value::Float(*lhs as f32 + rhs)),
+                Value::String(rhs) => Ok(Value::String(lhs.to_string() + &rhs)),
+                other => Err(format!("cannot add int to {}", Type::simple(other))),
+            },
+            Value::Float(lhs) => match rhs {
+                Value::Int(rhs) => Ok(Value::Float(lhs + *rhs as f32)),
+                Value::Float(rhs) => Ok(Value::Float(lhs + rhs)),
+                Value::String(rhs) => Ok(Value::String(lhs.to_string() + &rhs)),
+                other => Err(format!("cannot add float to {}", Type::simple(other))),
+            },
+            Value::String(lhs) => match rhs {
+                Value::Int(rhs) => Ok(Value::String(lhs.to_owned() + &rhs.to_string().to_owned())),
+                Value::Float(rhs) => {
+                    Ok(Value::String(lhs.to_owned() + &rhs.to_string().to_owned()))
+                }
+                Value::String(rhs) => Ok(Value::String(lhs.to_owned() + rhs)),
+                Value::List(rhs) => Ok(Value::String(
+                    lhs.to_owned() + Value::List(rhs.to_owned()).to_string().as_str(),
+                )),
+                other => Err(format!("cannot add stirng to {}", Type::simple(other))),
+            },
+            other => Err(format!(
+                "cannot add {} to {}",
+                Type::simple(other),
+                Type::simple(rhs)
+            )),
+        }
+    }
+}
+
+impl Mul for &Value {
+    type Output = Result<Value, String>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match self {
+            Value::Int(lhs) => match rhs {
+                Value::Int(rhs) => match lhs.checked_mul(*rhs) {
+                    Some(n) => Ok(Value::Int(n)),
+                    None => Err(format!("integer overflow in {} * {}", lhs, rhs)),
+                },
+                Value::Float(rhs) => Ok(Value::Float(*lhs as f32 * rhs)),
+                other => Err(format!("cannot mul int to {}", Type::simple(other))),
+            },
+            Value::Float(lhs) => match rhs {
+                Value::Int(rhs) => Ok(Value::Float(lhs * *rhs as f32)),
+                Value::Float(rhs) => Ok(Value::Float(lhs * rhs)),
+                other => Err(format!("cannot mul float to {}", Type::simple(other))),
+            },
+            other => Err(format!(
+                "cannot mul {} to {}",
+                Type::simple(other),
+                Type::simple(rhs)
+            )),
+        }
+    }
+}
+
+impl Div for &Value {
+    type Output = Result<Value, String>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        match self {
+            Value::Int(lhs) => match rhs {
+                Value::Int(rhs) => match lhs.checked_div(*rhs) {
+                    Some(n) => Ok(Value::Int(n)),
+                    None if *rhs == 0 => Err(format!("cannot divide {} by zero", lhs)),
+                    None => Err(format!("integer overflow in {} / {}", lhs, rhs)),
+                },
+                Value::Float(rhs) => Ok(Value::Float(*lhs as f32 / rhs)),
+                other => Err(format!("cannot div int to {}", Type::simple(other))),
+            },
+            Value::Float(lhs) => match rhs {
+                Value::Int(rhs) => Ok(Value::Float(lhs / *rhs as f32)),
+                Value::Float(rhs) => Ok(Value::Float(lhs / rhs)),
+                other => Err(format!("cannot div float to {}", Type::simple(other))),
+            },
+            other => Err(format!(
+                "cannot div {} to {}",
+                Type::simple(other),
+                Type::simple(rhs)
+            )),
+        }
+    }
+}
+
+impl Sub for &Value {
+    type Output = Result<Value, String>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self {
+            Value::Int(lhs) => match rhs {
+                Value::Int(rhs) => match lhs.checked_sub(*rhs) {
+                    Some(n) => Ok(Value::Int(n)),
+                    None => Err(format!("integer overflow in {} - {}", lhs, rhs)),
+                },
+                Value::Float(rhs) => Ok(Value::Float(*lhs as f32 - rhs)),
+                other => Err(format!("cannot sub float to {}", Type::simple(other))),
+            },
+            Value::Float(lhs) => match rhs {
+                Value::Int(rhs) => Ok(Value::Float(lhs - *rhs as f32)),
+                Value::Float(rhs) => Ok(Value::Float(lhs - rhs)),
+                other => Err(format!("cannot sub float to {}", Type::simple(other))),
+            },
+            other => Err(format!(
+                "cannot sub {} to {}",
+                Type::simple(other),
+                Type::simple(rhs)
+            )),
+        }
+    }
+}